@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+/// An error encountered while loading a Windows metadata file
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    /// The file is not a PE (portable executable) file
+    NotPe { path: PathBuf, offset: u32 },
+    /// The PE file has no CLR header and so contains no metadata
+    NoClrHeader { path: PathBuf, offset: u32 },
+    /// The metadata root or one of its streams is invalid
+    BadStream { path: PathBuf, offset: u32 },
+    /// The metadata tables are invalid
+    BadTable { path: PathBuf, offset: u32 },
+    /// The file ends before the data expected at the offset
+    Truncated { path: PathBuf, offset: u32 },
+}
+
+impl Error {
+    /// The path of the file that could not be loaded
+    pub fn path(&self) -> &Path {
+        match self {
+            Error::Io { path, .. }
+            | Error::NotPe { path, .. }
+            | Error::NoClrHeader { path, .. }
+            | Error::BadStream { path, .. }
+            | Error::BadTable { path, .. }
+            | Error::Truncated { path, .. } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Io { path, error } => write!(f, "Could not read file {:?}: {}", path, error),
+            Error::NotPe { path, offset } => write!(
+                f,
+                "Invalid file {:?}: not a PE file (offset {})",
+                path, offset
+            ),
+            Error::NoClrHeader { path, offset } => write!(
+                f,
+                "Invalid file {:?}: no CLR header (offset {})",
+                path, offset
+            ),
+            Error::BadStream { path, offset } => write!(
+                f,
+                "Invalid file {:?}: invalid metadata stream (offset {})",
+                path, offset
+            ),
+            Error::BadTable { path, offset } => write!(
+                f,
+                "Invalid file {:?}: invalid metadata table (offset {})",
+                path, offset
+            ),
+            Error::Truncated { path, offset } => write!(
+                f,
+                "Invalid file {:?}: unexpected end of file (offset {})",
+                path, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::traits::*;
+use crate::{Column, ColumnKind, Error};
+use std::path::Path;
 
-#[derive(Default)]
+#[derive(Copy, Clone, Default)]
 pub struct TableData {
     pub data: u32,
     pub row_count: u32,
@@ -9,18 +11,29 @@ pub struct TableData {
 }
 
 /// A Windows Metadata File
-#[derive(Default)]
 pub struct File {
     /// The files raw bytes
     pub(crate) bytes: Vec<u8>,
     /// The index of the strings data
     pub(crate) strings: u32,
+    /// The size of the strings data
+    pub(crate) strings_size: u32,
     /// The index of the blobs data
     pub(crate) blobs: u32,
+    /// The size of the blobs data
+    pub(crate) blobs_size: u32,
     /// The index of the guids data
     pub(crate) guids: u32,
+    /// The size of the guids data
+    pub(crate) guids_size: u32,
+    /// The index of the user strings data
+    pub(crate) user_strings: u32,
+    /// The size of the user strings data
+    pub(crate) user_strings_size: u32,
+    /// The section headers used to map relative virtual addresses to file offsets
+    pub(crate) sections: Vec<ImageSectionHeader>,
     /// The table data
-    pub(crate) tables: [TableData; 11],
+    pub(crate) tables: [TableData; TABLE_COUNT],
 }
 
 /// A well-known index of data into the winmd tables array
@@ -38,8 +51,38 @@ pub enum TableIndex {
     TypeDef,
     TypeRef,
     TypeSpec,
+    // Later tables are appended, in ECMA-335 id order, to keep the discriminants above stable.
+    Module,
+    FieldMarshal,
+    DeclSecurity,
+    ClassLayout,
+    FieldLayout,
+    StandaloneSig,
+    EventMap,
+    Event,
+    PropertyMap,
+    Property,
+    MethodSemantics,
+    MethodImpl,
+    ModuleRef,
+    ImplMap,
+    FieldRva,
+    Assembly,
+    AssemblyProcessor,
+    AssemblyOs,
+    AssemblyRef,
+    AssemblyRefProcessor,
+    AssemblyRefOs,
+    File,
+    ExportedType,
+    ManifestResource,
+    NestedClass,
+    MethodSpec,
+    GenericParamConstraint,
 }
 
+pub(crate) const TABLE_COUNT: usize = TableIndex::GenericParamConstraint as usize + 1;
+
 impl TableData {
     fn index_size(&self) -> u32 {
         if self.row_count < (1 << 16) {
@@ -78,179 +121,237 @@ impl TableData {
     }
 }
 
+impl Default for File {
+    fn default() -> Self {
+        Self {
+            bytes: Vec::default(),
+            strings: 0,
+            strings_size: 0,
+            blobs: 0,
+            blobs_size: 0,
+            guids: 0,
+            guids_size: 0,
+            user_strings: 0,
+            user_strings_size: 0,
+            sections: Vec::default(),
+            tables: [TableData::default(); TABLE_COUNT],
+        }
+    }
+}
+
 impl File {
     /// Parse a Windows metadata file at the given path
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the file at the path cannot be read or if there is a fatal error when parsing the file
-    pub(crate) fn new<P: AsRef<std::path::Path>>(filename: P) -> Self {
-        let bytes = std::fs::read(filename.as_ref())
-            .unwrap_or_else(|e| panic!("Could not read file {:?}: {:?}", filename.as_ref(), e));
+    /// Returns an [`Error`] if the file at the path cannot be read or is not a valid metadata file
+    pub fn try_new<P: AsRef<Path>>(filename: P) -> Result<Self, Error> {
+        let path = filename.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| Error::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
         let mut file = Self {
             bytes,
             ..Default::default()
         };
-        let dos = file.bytes.view_as::<ImageDosHeader>(0);
+
+        let not_pe = |offset| Error::NotPe {
+            path: path.to_path_buf(),
+            offset,
+        };
+        let no_clr_header = |offset| Error::NoClrHeader {
+            path: path.to_path_buf(),
+            offset,
+        };
+        let bad_stream = |offset| Error::BadStream {
+            path: path.to_path_buf(),
+            offset,
+        };
+        let bad_table = |offset| Error::BadTable {
+            path: path.to_path_buf(),
+            offset,
+        };
+        let truncated = |offset| Error::Truncated {
+            path: path.to_path_buf(),
+            offset,
+        };
+
+        let dos = file
+            .bytes
+            .try_view_as::<ImageDosHeader>(0)
+            .ok_or_else(|| not_pe(0))?;
 
         if dos.signature != IMAGE_DOS_SIGNATURE {
-            panic!(
-                "Invalid file: file does not appear to be a winmd file - '{:?}'",
-                filename.as_ref()
-            );
+            return Err(not_pe(0));
         }
 
-        let pe = file.bytes.view_as::<ImageNtHeader>(dos.lfanew as u32);
+        let pe_offset = dos.lfanew as u32;
+        let pe = file
+            .bytes
+            .try_view_as::<ImageNtHeader>(pe_offset)
+            .ok_or_else(|| not_pe(pe_offset))?;
+
+        if pe.signature != IMAGE_NT_SIGNATURE {
+            return Err(not_pe(pe_offset));
+        }
 
         let (com_virtual_address, sections) = match pe.optional_header.magic {
             MAGIC_PE32 => (
                 pe.optional_header.data_directory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                     .virtual_address,
-                file.bytes.view_as_slice_of::<ImageSectionHeader>(
-                    dos.lfanew as u32 + sizeof::<ImageNtHeader>(),
+                file.bytes.try_view_as_slice_of::<ImageSectionHeader>(
+                    pe_offset
+                        .checked_add(sizeof::<ImageNtHeader>())
+                        .ok_or_else(|| truncated(pe_offset))?,
                     pe.file_header.number_of_sections as u32,
                 ),
             ),
             MAGIC_PE32PLUS => (
                 file.bytes
-                    .view_as::<ImageNtHeaderPlus>(dos.lfanew as u32)
+                    .try_view_as::<ImageNtHeaderPlus>(pe_offset)
+                    .ok_or_else(|| truncated(pe_offset))?
                     .optional_header
                     .data_directory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize]
                     .virtual_address,
-                file.bytes.view_as_slice_of::<ImageSectionHeader>(
-                    dos.lfanew as u32 + sizeof::<ImageNtHeaderPlus>(),
+                file.bytes.try_view_as_slice_of::<ImageSectionHeader>(
+                    pe_offset
+                        .checked_add(sizeof::<ImageNtHeaderPlus>())
+                        .ok_or_else(|| truncated(pe_offset))?,
                     pe.file_header.number_of_sections as u32,
                 ),
             ),
-            _ => panic!("Invalid file: invalid magic"),
+            _ => return Err(not_pe(pe_offset)),
         };
 
-        let cli = file.bytes.view_as::<ImageCorHeader>(offset_from_rva(
-            section_from_rva(sections, com_virtual_address),
-            com_virtual_address,
-        ));
+        let sections = sections.ok_or_else(|| truncated(pe_offset))?;
+        file.sections = sections.to_vec();
+
+        if com_virtual_address == 0 {
+            return Err(no_clr_header(pe_offset));
+        }
+
+        let cli_header_offset = try_offset_from_rva(sections, com_virtual_address)
+            .ok_or_else(|| no_clr_header(pe_offset))?;
+        let cli = file
+            .bytes
+            .try_view_as::<ImageCorHeader>(cli_header_offset)
+            .ok_or_else(|| truncated(cli_header_offset))?;
 
         if cli.cb != sizeof::<ImageCorHeader>() {
-            panic!("Invalid file: invalid ImageCorHeader");
+            return Err(no_clr_header(cli_header_offset));
         }
 
-        let cli_offset = offset_from_rva(
-            section_from_rva(sections, cli.meta_data.virtual_address),
-            cli.meta_data.virtual_address,
-        );
+        let cli_offset = try_offset_from_rva(sections, cli.meta_data.virtual_address)
+            .ok_or_else(|| bad_stream(cli_header_offset))?;
 
-        if file.bytes.copy_as::<u32>(cli_offset) != STORAGE_MAGIC_SIG {
-            panic!("Invalid file: invalid STORAGE_MAGIC_SIG");
+        if file.bytes.try_copy_as::<u32>(cli_offset) != Some(STORAGE_MAGIC_SIG) {
+            return Err(bad_stream(cli_offset));
         }
 
-        let version_length = file.bytes.copy_as::<u32>(cli_offset + 12);
-        let mut view = cli_offset + version_length + 20;
-        let mut tables_data: (u32, u32) = (0, 0);
+        let version_length = cli_offset
+            .checked_add(12)
+            .and_then(|offset| file.bytes.try_copy_as::<u32>(offset))
+            .ok_or_else(|| truncated(cli_offset))?;
+        let stream_count_offset = cli_offset
+            .checked_add(version_length)
+            .and_then(|offset| offset.checked_add(18))
+            .ok_or_else(|| bad_stream(cli_offset))?;
+        let stream_count = file
+            .bytes
+            .try_copy_as::<u16>(stream_count_offset)
+            .ok_or_else(|| truncated(stream_count_offset))?;
+        let mut view = stream_count_offset
+            .checked_add(2)
+            .ok_or_else(|| truncated(stream_count_offset))?;
+        let mut tables_data: Option<(u32, u32)> = None;
+
+        for _ in 0..stream_count {
+            let stream_offset = file
+                .bytes
+                .try_copy_as::<u32>(view)
+                .ok_or_else(|| truncated(view))?;
+            let stream_size = view
+                .checked_add(4)
+                .and_then(|offset| file.bytes.try_copy_as::<u32>(offset))
+                .ok_or_else(|| truncated(view))?;
+            let stream_name = file
+                .bytes
+                .try_view_as_str(view.checked_add(8).ok_or_else(|| truncated(view))?)
+                .ok_or_else(|| truncated(view))?;
+
+            if cli_offset as u64 + stream_offset as u64 + stream_size as u64
+                > file.bytes.len() as u64
+            {
+                return Err(truncated(view));
+            }
 
-        for _ in 0..file.bytes.copy_as::<u16>(cli_offset + version_length + 18) {
-            let stream_offset = file.bytes.copy_as::<u32>(view);
-            let stream_size = file.bytes.copy_as::<u32>(view + 4);
-            let stream_name = file.bytes.view_as_str(view + 8);
             match stream_name {
-                b"#Strings" => file.strings = cli_offset + stream_offset,
-                b"#Blob" => file.blobs = cli_offset + stream_offset,
-                b"#GUID" => file.guids = cli_offset + stream_offset,
-                b"#~" => tables_data = (cli_offset + stream_offset, stream_size),
-                b"#US" => {}
-                _ => panic!("Invalid file: invalid stream name"),
+                b"#Strings" => {
+                    file.strings = cli_offset + stream_offset;
+                    file.strings_size = stream_size;
+                }
+                b"#Blob" => {
+                    file.blobs = cli_offset + stream_offset;
+                    file.blobs_size = stream_size;
+                }
+                b"#GUID" => {
+                    file.guids = cli_offset + stream_offset;
+                    file.guids_size = stream_size;
+                }
+                b"#~" => tables_data = Some((cli_offset + stream_offset, stream_size)),
+                b"#US" => {
+                    file.user_strings = cli_offset + stream_offset;
+                    file.user_strings_size = stream_size;
+                }
+                _ => return Err(bad_stream(view)),
             }
             let mut padding = 4 - stream_name.len() % 4;
             if padding == 0 {
                 padding = 4;
             }
-            view += (8 + stream_name.len() + padding) as u32;
+            view = view
+                .checked_add((8 + stream_name.len() + padding) as u32)
+                .ok_or_else(|| bad_stream(view))?;
         }
 
-        let heap_sizes = file.bytes.copy_as::<u8>(tables_data.0 + 6);
+        let tables_data = tables_data.ok_or_else(|| bad_stream(cli_offset))?;
+
+        let heap_sizes = tables_data
+            .0
+            .checked_add(6)
+            .and_then(|offset| file.bytes.try_copy_as::<u8>(offset))
+            .ok_or_else(|| truncated(tables_data.0))?;
         let string_index_size = if (heap_sizes & 1) == 1 { 4 } else { 2 };
         let guid_index_size = if (heap_sizes >> 1 & 1) == 1 { 4 } else { 2 };
         let blob_index_size = if (heap_sizes >> 2 & 1) == 1 { 4 } else { 2 };
-        let valid_bits = file.bytes.copy_as::<u64>(tables_data.0 + 8);
-        view = tables_data.0 + 24;
-
-        // These tables are unused by WinRT, but needed temporarily to calculate sizes and offsets for subsequent tables.
+        let valid_bits = tables_data
+            .0
+            .checked_add(8)
+            .and_then(|offset| file.bytes.try_copy_as::<u64>(offset))
+            .ok_or_else(|| truncated(tables_data.0))?;
+        view = tables_data
+            .0
+            .checked_add(24)
+            .ok_or_else(|| truncated(tables_data.0))?;
+
+        // Placeholder for the unused tags of the CustomAttributeType coded index.
         let unused_empty = TableData::default();
-        let mut unused_assembly = TableData::default();
-        let mut unused_assembly_os = TableData::default();
-        let mut unused_assembly_processor = TableData::default();
-        let mut unused_assembly_ref = TableData::default();
-        let mut unused_assembly_ref_os = TableData::default();
-        let mut unused_assembly_ref_processor = TableData::default();
-        let mut unused_class_layout = TableData::default();
-        let mut unused_decl_security = TableData::default();
-        let mut unused_event = TableData::default();
-        let mut unused_event_map = TableData::default();
-        let mut unused_exported_type = TableData::default();
-        let mut unused_field_layout = TableData::default();
-        let mut unused_field_marshal = TableData::default();
-        let mut unused_field_rva = TableData::default();
-        let mut unused_file = TableData::default();
-        let mut unused_generic_param_constraint = TableData::default();
-        let mut unused_impl_map = TableData::default();
-        let mut unused_manifest_resource = TableData::default();
-        let mut unused_method_impl = TableData::default();
-        let mut unused_method_semantics = TableData::default();
-        let mut unused_method_spec = TableData::default();
-        let mut unused_module = TableData::default();
-        let mut unused_module_ref = TableData::default();
-        let mut unused_nested_class = TableData::default();
-        let mut unused_property = TableData::default();
-        let mut unused_property_map = TableData::default();
-        let mut unused_standalone_sig = TableData::default();
 
         for i in 0..64 {
             if (valid_bits >> i & 1) == 0 {
                 continue;
             }
 
-            let row_count = file.bytes.copy_as::<u32>(view);
-            view += 4;
-
-            match i {
-                0x00 => unused_module.row_count = row_count,
-                0x01 => file.tables[TableIndex::TypeRef as usize].row_count = row_count,
-                0x02 => file.tables[TableIndex::TypeDef as usize].row_count = row_count,
-                0x04 => file.tables[TableIndex::Field as usize].row_count = row_count,
-                0x06 => file.tables[TableIndex::MethodDef as usize].row_count = row_count,
-                0x08 => file.tables[TableIndex::Param as usize].row_count = row_count,
-                0x09 => file.tables[TableIndex::InterfaceImpl as usize].row_count = row_count,
-                0x0a => file.tables[TableIndex::MemberRef as usize].row_count = row_count,
-                0x0b => file.tables[TableIndex::Constant as usize].row_count = row_count,
-                0x0c => file.tables[TableIndex::CustomAttribute as usize].row_count = row_count,
-                0x0d => unused_field_marshal.row_count = row_count,
-                0x0e => unused_decl_security.row_count = row_count,
-                0x0f => unused_class_layout.row_count = row_count,
-                0x10 => unused_field_layout.row_count = row_count,
-                0x11 => unused_standalone_sig.row_count = row_count,
-                0x12 => unused_event_map.row_count = row_count,
-                0x14 => unused_event.row_count = row_count,
-                0x15 => unused_property_map.row_count = row_count,
-                0x17 => unused_property.row_count = row_count,
-                0x18 => unused_method_semantics.row_count = row_count,
-                0x19 => unused_method_impl.row_count = row_count,
-                0x1a => unused_module_ref.row_count = row_count,
-                0x1b => file.tables[TableIndex::TypeSpec as usize].row_count = row_count,
-                0x1c => unused_impl_map.row_count = row_count,
-                0x1d => unused_field_rva.row_count = row_count,
-                0x20 => unused_assembly.row_count = row_count,
-                0x21 => unused_assembly_processor.row_count = row_count,
-                0x22 => unused_assembly_os.row_count = row_count,
-                0x23 => unused_assembly_ref.row_count = row_count,
-                0x24 => unused_assembly_ref_processor.row_count = row_count,
-                0x25 => unused_assembly_ref_os.row_count = row_count,
-                0x26 => unused_file.row_count = row_count,
-                0x27 => unused_exported_type.row_count = row_count,
-                0x28 => unused_manifest_resource.row_count = row_count,
-                0x29 => unused_nested_class.row_count = row_count,
-                0x2a => file.tables[TableIndex::GenericParam as usize].row_count = row_count,
-                0x2b => unused_method_spec.row_count = row_count,
-                0x2c => unused_generic_param_constraint.row_count = row_count,
-                _ => unreachable!(),
+            let row_count = file
+                .bytes
+                .try_copy_as::<u32>(view)
+                .ok_or_else(|| truncated(view))?;
+            view = view.checked_add(4).ok_or_else(|| truncated(view))?;
+
+            match TableIndex::from_id(i) {
+                Some(table) => file.tables[table as usize].row_count = row_count,
+                None => return Err(bad_table(tables_data.0 + 8)),
             };
         }
 
@@ -263,7 +364,7 @@ impl File {
         let has_constant = composite_index_size(&[
             &file.tables[TableIndex::Field as usize],
             &file.tables[TableIndex::Param as usize],
-            &unused_property,
+            &file.tables[TableIndex::Property as usize],
         ]);
 
         let has_custom_attribute = composite_index_size(&[
@@ -274,20 +375,20 @@ impl File {
             &file.tables[TableIndex::Param as usize],
             &file.tables[TableIndex::InterfaceImpl as usize],
             &file.tables[TableIndex::MemberRef as usize],
-            &unused_module,
-            &unused_property,
-            &unused_event,
-            &unused_standalone_sig,
-            &unused_module_ref,
+            &file.tables[TableIndex::Module as usize],
+            &file.tables[TableIndex::Property as usize],
+            &file.tables[TableIndex::Event as usize],
+            &file.tables[TableIndex::StandaloneSig as usize],
+            &file.tables[TableIndex::ModuleRef as usize],
             &file.tables[TableIndex::TypeSpec as usize],
-            &unused_assembly,
-            &unused_assembly_ref,
-            &unused_file,
-            &unused_exported_type,
-            &unused_manifest_resource,
+            &file.tables[TableIndex::Assembly as usize],
+            &file.tables[TableIndex::AssemblyRef as usize],
+            &file.tables[TableIndex::File as usize],
+            &file.tables[TableIndex::ExportedType as usize],
+            &file.tables[TableIndex::ManifestResource as usize],
             &file.tables[TableIndex::GenericParam as usize],
-            &unused_generic_param_constraint,
-            &unused_method_spec,
+            &file.tables[TableIndex::GenericParamConstraint as usize],
+            &file.tables[TableIndex::MethodSpec as usize],
         ]);
 
         let has_field_marshal = composite_index_size(&[
@@ -298,18 +399,21 @@ impl File {
         let has_decl_security = composite_index_size(&[
             &file.tables[TableIndex::TypeDef as usize],
             &file.tables[TableIndex::MethodDef as usize],
-            &unused_assembly,
+            &file.tables[TableIndex::Assembly as usize],
         ]);
 
         let member_ref_parent = composite_index_size(&[
             &file.tables[TableIndex::TypeDef as usize],
             &file.tables[TableIndex::TypeRef as usize],
-            &unused_module_ref,
+            &file.tables[TableIndex::ModuleRef as usize],
             &file.tables[TableIndex::MethodDef as usize],
             &file.tables[TableIndex::TypeSpec as usize],
         ]);
 
-        let has_semantics = composite_index_size(&[&unused_event, &unused_property]);
+        let has_semantics = composite_index_size(&[
+            &file.tables[TableIndex::Event as usize],
+            &file.tables[TableIndex::Property as usize],
+        ]);
 
         let method_def_or_ref = composite_index_size(&[
             &file.tables[TableIndex::MethodDef as usize],
@@ -321,8 +425,11 @@ impl File {
             &file.tables[TableIndex::MethodDef as usize],
         ]);
 
-        let implementation =
-            composite_index_size(&[&unused_file, &unused_assembly_ref, &unused_exported_type]);
+        let implementation = composite_index_size(&[
+            &file.tables[TableIndex::File as usize],
+            &file.tables[TableIndex::AssemblyRef as usize],
+            &file.tables[TableIndex::ExportedType as usize],
+        ]);
 
         let custom_attribute_type = composite_index_size(&[
             &file.tables[TableIndex::MethodDef as usize],
@@ -333,9 +440,9 @@ impl File {
         ]);
 
         let resolution_scope = composite_index_size(&[
-            &unused_module,
-            &unused_module_ref,
-            &unused_assembly_ref,
+            &file.tables[TableIndex::Module as usize],
+            &file.tables[TableIndex::ModuleRef as usize],
+            &file.tables[TableIndex::AssemblyRef as usize],
             &file.tables[TableIndex::TypeRef as usize],
         ]);
 
@@ -344,7 +451,7 @@ impl File {
             &file.tables[TableIndex::MethodDef as usize],
         ]);
 
-        unused_assembly.set_columns(
+        file.tables[TableIndex::Assembly as usize].set_columns(
             4,
             8,
             4,
@@ -352,9 +459,9 @@ impl File {
             string_index_size,
             string_index_size,
         );
-        unused_assembly_os.set_columns(4, 4, 4, 0, 0, 0);
-        unused_assembly_processor.set_columns(4, 0, 0, 0, 0, 0);
-        unused_assembly_ref.set_columns(
+        file.tables[TableIndex::AssemblyOs as usize].set_columns(4, 4, 4, 0, 0, 0);
+        file.tables[TableIndex::AssemblyProcessor as usize].set_columns(4, 0, 0, 0, 0, 0);
+        file.tables[TableIndex::AssemblyRef as usize].set_columns(
             8,
             4,
             blob_index_size,
@@ -362,9 +469,23 @@ impl File {
             string_index_size,
             blob_index_size,
         );
-        unused_assembly_ref_os.set_columns(4, 4, 4, unused_assembly_ref.index_size(), 0, 0);
-        unused_assembly_ref_processor.set_columns(4, unused_assembly_ref.index_size(), 0, 0, 0, 0);
-        unused_class_layout.set_columns(
+        file.tables[TableIndex::AssemblyRefOs as usize].set_columns(
+            4,
+            4,
+            4,
+            file.tables[TableIndex::AssemblyRef as usize].index_size(),
+            0,
+            0,
+        );
+        file.tables[TableIndex::AssemblyRefProcessor as usize].set_columns(
+            4,
+            file.tables[TableIndex::AssemblyRef as usize].index_size(),
+            0,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::ClassLayout as usize].set_columns(
             2,
             4,
             file.tables[TableIndex::TypeDef as usize].index_size(),
@@ -388,17 +509,31 @@ impl File {
             0,
             0,
         );
-        unused_decl_security.set_columns(2, has_decl_security, blob_index_size, 0, 0, 0);
-        unused_event_map.set_columns(
+        file.tables[TableIndex::DeclSecurity as usize].set_columns(
+            2,
+            has_decl_security,
+            blob_index_size,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::EventMap as usize].set_columns(
             file.tables[TableIndex::TypeDef as usize].index_size(),
-            unused_event.index_size(),
+            file.tables[TableIndex::Event as usize].index_size(),
             0,
             0,
             0,
             0,
         );
-        unused_event.set_columns(2, string_index_size, type_def_or_ref, 0, 0, 0);
-        unused_exported_type.set_columns(
+        file.tables[TableIndex::Event as usize].set_columns(
+            2,
+            string_index_size,
+            type_def_or_ref,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::ExportedType as usize].set_columns(
             4,
             4,
             string_index_size,
@@ -414,7 +549,7 @@ impl File {
             0,
             0,
         );
-        unused_field_layout.set_columns(
+        file.tables[TableIndex::FieldLayout as usize].set_columns(
             4,
             file.tables[TableIndex::Field as usize].index_size(),
             0,
@@ -422,8 +557,15 @@ impl File {
             0,
             0,
         );
-        unused_field_marshal.set_columns(has_field_marshal, blob_index_size, 0, 0, 0, 0);
-        unused_field_rva.set_columns(
+        file.tables[TableIndex::FieldMarshal as usize].set_columns(
+            has_field_marshal,
+            blob_index_size,
+            0,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::FieldRva as usize].set_columns(
             4,
             file.tables[TableIndex::Field as usize].index_size(),
             0,
@@ -431,7 +573,14 @@ impl File {
             0,
             0,
         );
-        unused_file.set_columns(4, string_index_size, blob_index_size, 0, 0, 0);
+        file.tables[TableIndex::File as usize].set_columns(
+            4,
+            string_index_size,
+            blob_index_size,
+            0,
+            0,
+            0,
+        );
         file.tables[TableIndex::GenericParam as usize].set_columns(
             2,
             2,
@@ -440,7 +589,7 @@ impl File {
             0,
             0,
         );
-        unused_generic_param_constraint.set_columns(
+        file.tables[TableIndex::GenericParamConstraint as usize].set_columns(
             file.tables[TableIndex::GenericParam as usize].index_size(),
             type_def_or_ref,
             0,
@@ -448,11 +597,11 @@ impl File {
             0,
             0,
         );
-        unused_impl_map.set_columns(
+        file.tables[TableIndex::ImplMap as usize].set_columns(
             2,
             member_forwarded,
             string_index_size,
-            unused_module_ref.index_size(),
+            file.tables[TableIndex::ModuleRef as usize].index_size(),
             0,
             0,
         );
@@ -464,7 +613,14 @@ impl File {
             0,
             0,
         );
-        unused_manifest_resource.set_columns(4, 4, string_index_size, implementation, 0, 0);
+        file.tables[TableIndex::ManifestResource as usize].set_columns(
+            4,
+            4,
+            string_index_size,
+            implementation,
+            0,
+            0,
+        );
         file.tables[TableIndex::MemberRef as usize].set_columns(
             member_ref_parent,
             string_index_size,
@@ -481,7 +637,7 @@ impl File {
             blob_index_size,
             file.tables[TableIndex::Param as usize].index_size(),
         );
-        unused_method_impl.set_columns(
+        file.tables[TableIndex::MethodImpl as usize].set_columns(
             file.tables[TableIndex::TypeDef as usize].index_size(),
             method_def_or_ref,
             method_def_or_ref,
@@ -489,7 +645,7 @@ impl File {
             0,
             0,
         );
-        unused_method_semantics.set_columns(
+        file.tables[TableIndex::MethodSemantics as usize].set_columns(
             2,
             file.tables[TableIndex::MethodDef as usize].index_size(),
             has_semantics,
@@ -497,8 +653,15 @@ impl File {
             0,
             0,
         );
-        unused_method_spec.set_columns(method_def_or_ref, blob_index_size, 0, 0, 0, 0);
-        unused_module.set_columns(
+        file.tables[TableIndex::MethodSpec as usize].set_columns(
+            method_def_or_ref,
+            blob_index_size,
+            0,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::Module as usize].set_columns(
             2,
             string_index_size,
             guid_index_size,
@@ -506,8 +669,8 @@ impl File {
             guid_index_size,
             0,
         );
-        unused_module_ref.set_columns(string_index_size, 0, 0, 0, 0, 0);
-        unused_nested_class.set_columns(
+        file.tables[TableIndex::ModuleRef as usize].set_columns(string_index_size, 0, 0, 0, 0, 0);
+        file.tables[TableIndex::NestedClass as usize].set_columns(
             file.tables[TableIndex::TypeDef as usize].index_size(),
             file.tables[TableIndex::TypeDef as usize].index_size(),
             0,
@@ -516,16 +679,23 @@ impl File {
             0,
         );
         file.tables[TableIndex::Param as usize].set_columns(2, 2, string_index_size, 0, 0, 0);
-        unused_property.set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
-        unused_property_map.set_columns(
+        file.tables[TableIndex::Property as usize].set_columns(
+            2,
+            string_index_size,
+            blob_index_size,
+            0,
+            0,
+            0,
+        );
+        file.tables[TableIndex::PropertyMap as usize].set_columns(
             file.tables[TableIndex::TypeDef as usize].index_size(),
-            unused_property.index_size(),
+            file.tables[TableIndex::Property as usize].index_size(),
             0,
             0,
             0,
             0,
         );
-        unused_standalone_sig.set_columns(blob_index_size, 0, 0, 0, 0, 0);
+        file.tables[TableIndex::StandaloneSig as usize].set_columns(blob_index_size, 0, 0, 0, 0, 0);
         file.tables[TableIndex::TypeDef as usize].set_columns(
             4,
             string_index_size,
@@ -544,7 +714,17 @@ impl File {
         );
         file.tables[TableIndex::TypeSpec as usize].set_columns(blob_index_size, 0, 0, 0, 0, 0);
 
-        unused_module.set_data(&mut view);
+        let tables_size: u64 = file
+            .tables
+            .iter()
+            .map(|table| table.row_count as u64 * table.row_size as u64)
+            .sum();
+
+        if view as u64 + tables_size > tables_data.0 as u64 + tables_data.1 as u64 {
+            return Err(bad_table(tables_data.0));
+        }
+
+        file.tables[TableIndex::Module as usize].set_data(&mut view);
         file.tables[TableIndex::TypeRef as usize].set_data(&mut view);
         file.tables[TableIndex::TypeDef as usize].set_data(&mut view);
         file.tables[TableIndex::Field as usize].set_data(&mut view);
@@ -554,52 +734,161 @@ impl File {
         file.tables[TableIndex::MemberRef as usize].set_data(&mut view);
         file.tables[TableIndex::Constant as usize].set_data(&mut view);
         file.tables[TableIndex::CustomAttribute as usize].set_data(&mut view);
-        unused_field_marshal.set_data(&mut view);
-        unused_decl_security.set_data(&mut view);
-        unused_class_layout.set_data(&mut view);
-        unused_field_layout.set_data(&mut view);
-        unused_standalone_sig.set_data(&mut view);
-        unused_event_map.set_data(&mut view);
-        unused_event.set_data(&mut view);
-        unused_property_map.set_data(&mut view);
-        unused_property.set_data(&mut view);
-        unused_method_semantics.set_data(&mut view);
-        unused_method_impl.set_data(&mut view);
-        unused_module_ref.set_data(&mut view);
+        file.tables[TableIndex::FieldMarshal as usize].set_data(&mut view);
+        file.tables[TableIndex::DeclSecurity as usize].set_data(&mut view);
+        file.tables[TableIndex::ClassLayout as usize].set_data(&mut view);
+        file.tables[TableIndex::FieldLayout as usize].set_data(&mut view);
+        file.tables[TableIndex::StandaloneSig as usize].set_data(&mut view);
+        file.tables[TableIndex::EventMap as usize].set_data(&mut view);
+        file.tables[TableIndex::Event as usize].set_data(&mut view);
+        file.tables[TableIndex::PropertyMap as usize].set_data(&mut view);
+        file.tables[TableIndex::Property as usize].set_data(&mut view);
+        file.tables[TableIndex::MethodSemantics as usize].set_data(&mut view);
+        file.tables[TableIndex::MethodImpl as usize].set_data(&mut view);
+        file.tables[TableIndex::ModuleRef as usize].set_data(&mut view);
         file.tables[TableIndex::TypeSpec as usize].set_data(&mut view);
-        unused_impl_map.set_data(&mut view);
-        unused_field_rva.set_data(&mut view);
-        unused_assembly.set_data(&mut view);
-        unused_assembly_processor.set_data(&mut view);
-        unused_assembly_os.set_data(&mut view);
-        unused_assembly_ref.set_data(&mut view);
-        unused_assembly_ref_processor.set_data(&mut view);
-        unused_assembly_ref_os.set_data(&mut view);
-        unused_file.set_data(&mut view);
-        unused_exported_type.set_data(&mut view);
-        unused_manifest_resource.set_data(&mut view);
-        unused_nested_class.set_data(&mut view);
+        file.tables[TableIndex::ImplMap as usize].set_data(&mut view);
+        file.tables[TableIndex::FieldRva as usize].set_data(&mut view);
+        file.tables[TableIndex::Assembly as usize].set_data(&mut view);
+        file.tables[TableIndex::AssemblyProcessor as usize].set_data(&mut view);
+        file.tables[TableIndex::AssemblyOs as usize].set_data(&mut view);
+        file.tables[TableIndex::AssemblyRef as usize].set_data(&mut view);
+        file.tables[TableIndex::AssemblyRefProcessor as usize].set_data(&mut view);
+        file.tables[TableIndex::AssemblyRefOs as usize].set_data(&mut view);
+        file.tables[TableIndex::File as usize].set_data(&mut view);
+        file.tables[TableIndex::ExportedType as usize].set_data(&mut view);
+        file.tables[TableIndex::ManifestResource as usize].set_data(&mut view);
+        file.tables[TableIndex::NestedClass as usize].set_data(&mut view);
         file.tables[TableIndex::GenericParam as usize].set_data(&mut view);
+        file.tables[TableIndex::MethodSpec as usize].set_data(&mut view);
+        file.tables[TableIndex::GenericParamConstraint as usize].set_data(&mut view);
 
-        file
+        file.validate().map_err(bad_table)?;
+        Ok(file)
+    }
+
+    /// Checks that every heap index, table index and coded index in the tables refers to data
+    /// in the file, so that reading the tables later can't go out of bounds. Returns the offset
+    /// of the first invalid value.
+    fn validate(&self) -> Result<(), u32> {
+        for table in (0..64).filter_map(TableIndex::from_id) {
+            let data = &self.tables[table as usize];
+
+            for (column, (offset, size)) in table.columns().iter().zip(&data.columns) {
+                // Test files may leave out trailing columns.
+                if *size == 0 {
+                    continue;
+                }
+
+                for row in 0..data.row_count {
+                    let offset = data.data + row * data.row_size + offset;
+                    let value = match size {
+                        2 => self.bytes.copy_as::<u16>(offset) as u32,
+                        4 => self.bytes.copy_as::<u32>(offset),
+                        _ => continue,
+                    };
+
+                    if !self.is_valid(table, column, value) {
+                        return Err(offset);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_valid(&self, table: TableIndex, column: &Column, value: u32) -> bool {
+        // Columns that ECMA-335 allows to be null. Every other table and coded index refers to
+        // a row, and list columns may point one past the last row of an empty list.
+        let nullable = matches!(
+            (table, column.name),
+            (TableIndex::TypeDef, "Extends")
+                | (TableIndex::TypeRef, "ResolutionScope")
+                | (TableIndex::ImplMap, "ImportScope")
+                | (TableIndex::ManifestResource, "Implementation")
+        );
+
+        let row_count = |table: TableIndex| self.tables[table as usize].row_count;
+
+        match column.kind {
+            ColumnKind::Integer => true,
+            ColumnKind::String => self.heap_str(value).is_some(),
+            ColumnKind::Guid => value as u64 * 16 <= self.guids_size as u64,
+            ColumnKind::Blob => self.heap_blob(value).is_some(),
+            ColumnKind::Table(target) => {
+                let last = if column.name.ends_with("List") {
+                    row_count(target) as u64 + 1
+                } else {
+                    row_count(target) as u64
+                };
+                (value == 0 && nullable) || (1..=last).contains(&(value as u64))
+            }
+            ColumnKind::Coded(coded) => {
+                let bits = coded.tag_bits();
+                let tag = value & ((1 << bits) - 1);
+                let index = value >> bits;
+
+                match coded.tables().get(tag as usize) {
+                    _ if value == 0 => nullable,
+                    Some(Some(target)) => index >= 1 && index <= row_count(*target),
+                    _ => false,
+                }
+            }
+        }
+    }
+
+    /// The NUL-terminated UTF-8 string at the index of the `#Strings` heap
+    fn heap_str(&self, index: u32) -> Option<&str> {
+        let heap = self.heap(self.strings, self.strings_size)?;
+        let bytes = heap.get(index as usize..)?;
+        let len = bytes.iter().position(|c| *c == b'\0')?;
+        std::str::from_utf8(&bytes[..len]).ok()
+    }
+
+    /// The blob at the index of the `#Blob` heap, without its length prefix
+    fn heap_blob(&self, index: u32) -> Option<&[u8]> {
+        let heap = self.heap(self.blobs, self.blobs_size)?;
+        let bytes = heap.get(index as usize..)?;
+        let first = *bytes.first()? as usize;
+        let (len, prefix) = match first >> 5 {
+            0..=3 => (first & 0x7f, 1),
+            4..=5 => (first & 0x3f, 2),
+            6 => (first & 0x1f, 4),
+            _ => return None,
+        };
+        let len = bytes
+            .get(1..prefix)?
+            .iter()
+            .fold(len, |len, byte| (len << 8) + *byte as usize);
+        bytes.get(prefix..prefix + len)
+    }
+
+    fn heap(&self, offset: u32, size: u32) -> Option<&[u8]> {
+        self.bytes
+            .get(offset as usize..offset as usize + size as usize)
     }
 
     pub(crate) fn type_def_table(&self) -> &TableData {
         &self.tables[TableIndex::TypeDef as usize]
     }
+
+    pub(crate) fn try_offset_from_rva(&self, rva: u32) -> Option<u32> {
+        try_offset_from_rva(&self.sections, rva)
+    }
 }
 
-fn section_from_rva(sections: &[ImageSectionHeader], rva: u32) -> &ImageSectionHeader {
+fn try_offset_from_rva(sections: &[ImageSectionHeader], rva: u32) -> Option<u32> {
     sections
         .iter()
         .find(|&s| {
-            rva >= s.virtual_address && rva < s.virtual_address + s.physical_address_or_virtual_size
+            rva >= s.virtual_address && rva - s.virtual_address < s.physical_address_or_virtual_size
         })
-        .expect("Invalid file")
+        .and_then(|section| offset_from_rva(section, rva))
 }
 
-fn offset_from_rva(section: &ImageSectionHeader, rva: u32) -> u32 {
-    rva - section.virtual_address + section.pointer_to_raw_data
+fn offset_from_rva(section: &ImageSectionHeader, rva: u32) -> Option<u32> {
+    (rva - section.virtual_address).checked_add(section.pointer_to_raw_data)
 }
 
 fn sizeof<T>() -> u32 {
@@ -647,36 +936,7 @@ macro_rules! assert_proper_length {
     };
 }
 
-macro_rules! assert_proper_length_and_alignment {
-    ($self:expr, $t:ty, $cli_offset:expr, $size:expr) => {{
-        assert_proper_length!($self, $t, $cli_offset, $size);
-
-        let ptr = &$self[$cli_offset as usize] as *const u8 as *const $t;
-
-        let properly_aligned = ptr.align_offset(std::mem::align_of::<$t>()) == 0;
-
-        assert!(
-            properly_aligned,
-            "Invalid file: offset {} is not properly aligned to T",
-            $cli_offset
-        );
-        ptr
-    }};
-}
-
 impl View for [u8] {
-    fn view_as<T: Pod>(&self, cli_offset: u32) -> &T {
-        let ptr = assert_proper_length_and_alignment!(self, T, cli_offset, sizeof::<T>());
-
-        unsafe { &*ptr }
-    }
-
-    fn view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> &[T] {
-        let ptr = assert_proper_length_and_alignment!(self, T, cli_offset, sizeof::<T>() * len);
-
-        unsafe { std::slice::from_raw_parts(ptr, len as usize) }
-    }
-
     fn copy_as<T: CopyPod>(&self, cli_offset: u32) -> T {
         assert_proper_length!(self, T, cli_offset, sizeof::<T>());
 
@@ -693,17 +953,50 @@ impl View for [u8] {
         }
     }
 
-    fn view_as_str(&self, cli_offset: u32) -> &[u8] {
-        let buffer = &self[cli_offset as usize..];
-        let index = buffer
-            .iter()
-            .position(|c| *c == b'\0')
-            .expect("Invalid file");
-        &self[cli_offset as usize..cli_offset as usize + index]
+    fn try_view_as<T: Pod>(&self, cli_offset: u32) -> Option<&T> {
+        let ptr = checked_ptr::<T>(self, cli_offset, sizeof::<T>() as u64)?;
+
+        unsafe { Some(&*ptr) }
+    }
+
+    fn try_view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> Option<&[T]> {
+        let ptr = checked_ptr::<T>(self, cli_offset, sizeof::<T>() as u64 * len as u64)?;
+
+        unsafe { Some(std::slice::from_raw_parts(ptr, len as usize)) }
+    }
+
+    fn try_copy_as<T: CopyPod>(&self, cli_offset: u32) -> Option<T> {
+        if cli_offset as u64 + sizeof::<T>() as u64 > self.len() as u64 {
+            return None;
+        }
+
+        Some(self.copy_as(cli_offset))
+    }
+
+    fn try_view_as_str(&self, cli_offset: u32) -> Option<&[u8]> {
+        let buffer = self.get(cli_offset as usize..)?;
+        let index = buffer.iter().position(|c| *c == b'\0')?;
+        Some(&buffer[..index])
+    }
+}
+
+/// Returns a pointer to `size` bytes at the offset if they are in bounds and properly aligned to T.
+fn checked_ptr<T>(bytes: &[u8], cli_offset: u32, size: u64) -> Option<*const T> {
+    if cli_offset as u64 + size > bytes.len() as u64 {
+        return None;
+    }
+
+    let ptr = bytes[cli_offset as usize..].as_ptr() as *const T;
+
+    if ptr.align_offset(std::mem::align_of::<T>()) != 0 {
+        return None;
     }
+
+    Some(ptr)
 }
 
 const IMAGE_DOS_SIGNATURE: u16 = 0x5A4D;
+const IMAGE_NT_SIGNATURE: u32 = 0x0000_4550;
 const MAGIC_PE32: u16 = 0x10B;
 const MAGIC_PE32PLUS: u16 = 0x20B;
 const IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR: u32 = 14;
@@ -848,7 +1141,8 @@ struct ImageNtHeaderPlus {
 unsafe impl Pod for ImageNtHeaderPlus {}
 
 #[repr(C)]
-struct ImageSectionHeader {
+#[derive(Clone)]
+pub(crate) struct ImageSectionHeader {
     name: [u8; 8],
     physical_address_or_virtual_size: u32,
     virtual_address: u32,
@@ -883,6 +1177,107 @@ struct ImageCorHeader {
 // and only contains data that is itself `Pod`
 unsafe impl Pod for ImageCorHeader {}
 
+/// Builds a metadata file in memory for unit tests that need rows or blobs the test metadata
+/// doesn't have
+///
+/// The file holds a `#Strings` heap, a `#Blob` heap and the given tables, in that order. A
+/// column is two bytes wide unless one of its values needs four.
+#[cfg(test)]
+pub(crate) struct TestFile {
+    strings: Vec<u8>,
+    blobs: Vec<u8>,
+    tables: Vec<(TableIndex, Vec<Vec<u32>>)>,
+}
+
+#[cfg(test)]
+impl TestFile {
+    pub(crate) fn new() -> Self {
+        Self {
+            strings: vec![0],
+            blobs: vec![0],
+            tables: Vec::new(),
+        }
+    }
+
+    /// Appends a string to the `#Strings` heap and returns its index
+    pub(crate) fn string(&mut self, value: &str) -> u32 {
+        let index = self.strings.len() as u32;
+        self.strings.extend(value.as_bytes());
+        self.strings.push(0);
+        index
+    }
+
+    /// Appends a blob to the `#Blob` heap and returns its index
+    pub(crate) fn blob(&mut self, value: &[u8]) -> u32 {
+        assert!(value.len() < 0x4000);
+        let index = self.blobs.len() as u32;
+        if value.len() < 0x80 {
+            self.blobs.push(value.len() as u8);
+        } else {
+            self.blobs.push(0x80 | (value.len() >> 8) as u8);
+            self.blobs.push(value.len() as u8);
+        }
+        self.blobs.extend(value);
+        index
+    }
+
+    /// Sets the rows of a table, one value per column
+    pub(crate) fn table(&mut self, table: TableIndex, rows: &[&[u32]]) {
+        self.tables
+            .push((table, rows.iter().map(|row| row.to_vec()).collect()));
+    }
+
+    /// Builds the file
+    pub(crate) fn build(self) -> File {
+        let mut file = File {
+            strings: 0,
+            strings_size: self.strings.len() as u32,
+            blobs: self.strings.len() as u32,
+            blobs_size: self.blobs.len() as u32,
+            bytes: self.strings,
+            ..Default::default()
+        };
+        file.bytes.extend(self.blobs);
+
+        for (table, rows) in self.tables {
+            let column_count = rows.first().map_or(0, |row| row.len());
+            assert!(column_count <= 6);
+
+            let data = &mut file.tables[table as usize];
+            data.data = file.bytes.len() as u32;
+            data.row_count = rows.len() as u32;
+
+            for column in 0..column_count {
+                let wide = rows.iter().any(|row| row[column] > 0xFFFF);
+                data.columns[column] = (data.row_size, if wide { 4 } else { 2 });
+                data.row_size += data.columns[column].1;
+            }
+
+            for row in rows {
+                for (column, value) in row.iter().enumerate() {
+                    match data.columns[column].1 {
+                        2 => file.bytes.extend(&(*value as u16).to_le_bytes()),
+                        _ => file.bytes.extend(&value.to_le_bytes()),
+                    }
+                }
+            }
+        }
+
+        if let Err(offset) = file.validate() {
+            panic!("Invalid test table value at offset {}", offset);
+        }
+
+        file
+    }
+
+    /// Builds the file and loads it into a reader of its own
+    pub(crate) fn reader(self) -> crate::TypeReader {
+        let mut reader = crate::TypeReader::try_from_iter(Vec::new()).unwrap();
+        reader.insert_test_file(self.build());
+        reader
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -896,28 +1291,33 @@ mod tests {
 
     #[test]
     fn view_bytes_as_type() {
-        let bytes = [1u8, 3, 48, 90];
+        // The bytes [1, 3, 48, 90] stored with the alignment of Foo.
+        let bytes = [0x0301u16, 0x5A30];
+        let bytes = unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u8, 4) };
 
-        let foo = bytes.view_as::<Foo>(0);
+        let foo = bytes.try_view_as::<Foo>(0).unwrap();
         assert_eq!(foo.bar, 0x0301);
         assert_eq!(foo.baz, 48)
     }
 
     #[test]
-    #[should_panic]
-    fn panic_on_unaligned_bytes() {
-        let bytes = [1u8, 3, 48, 90, 90];
-
-        let _ = bytes.view_as::<Foo>(0);
-        let _ = bytes.view_as::<Foo>(1);
+    fn none_on_unaligned_bytes() {
+        // The bytes [1, 3, 48, 90, 90, 90] stored with the alignment of Foo, so that offset 1 is
+        // always misaligned.
+        let bytes = [0x0301u16, 0x5A30, 0x5A5A];
+        let bytes = unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u8, 6) };
+
+        assert!(bytes.try_view_as::<Foo>(0).is_some());
+        assert!(bytes.try_view_as::<Foo>(1).is_none());
     }
 
     #[test]
-    #[should_panic]
-    fn panic_on_too_few_bytes() {
+    fn none_on_too_few_bytes() {
         let bytes = [1u8, 3];
 
-        let _ = bytes.view_as::<Foo>(0);
+        assert!(bytes.try_view_as::<Foo>(0).is_none());
+        assert!(bytes.try_copy_as::<u32>(0).is_none());
+        assert!(bytes.try_view_as_str(0).is_none());
     }
 
     #[test]
@@ -927,4 +1327,61 @@ mod tests {
         let foo = bytes.copy_as::<u16>(0);
         assert_eq!(foo, 0x0301);
     }
+
+    #[test]
+    fn none_on_rva_outside_sections() {
+        let file = File::try_new("winmds/Windows.Foundation.FoundationContract.winmd").unwrap();
+        let section = &file.sections[0];
+
+        assert_eq!(
+            file.try_offset_from_rva(section.virtual_address),
+            Some(section.pointer_to_raw_data)
+        );
+        assert_eq!(file.try_offset_from_rva(0xFFFF_FFFF), None);
+    }
+
+    #[test]
+    fn none_on_rva_overflow() {
+        // A section whose virtual range and raw data both run past the end of the address space.
+        let sections = [ImageSectionHeader {
+            name: *b".text\0\0\0",
+            physical_address_or_virtual_size: 0x1000,
+            virtual_address: 0xFFFF_FF00,
+            size_of_raw_data: 0x1000,
+            pointer_to_raw_data: 0xFFFF_FFF0,
+            pointer_to_relocations: 0,
+            pointer_to_line_numbers: 0,
+            number_of_relocations: 0,
+            number_of_line_numbers: 0,
+            characteristics: 0,
+        }];
+
+        assert_eq!(
+            try_offset_from_rva(&sections, 0xFFFF_FF00),
+            Some(0xFFFF_FFF0)
+        );
+        assert_eq!(try_offset_from_rva(&sections, 0xFFFF_FFFF), None);
+        assert_eq!(try_offset_from_rva(&sections, 0xFFFF_FE00), None);
+    }
+
+    #[test]
+    fn invalid_table_values() {
+        let valid = File::try_new("winmds/Windows.Foundation.FoundationContract.winmd").unwrap();
+        assert_eq!(valid.validate(), Ok(()));
+
+        // Points the first PropertyMap row at a null parent.
+        let mut file = File::try_new("winmds/Windows.Foundation.FoundationContract.winmd").unwrap();
+        let offset = file.tables[TableIndex::PropertyMap as usize].data;
+        file.bytes[offset as usize..offset as usize + 2].fill(0);
+        assert_eq!(file.validate(), Err(offset));
+
+        // Points the name of the first TypeDef row past the end of the #Strings heap.
+        let mut file = File::try_new("winmds/Windows.Foundation.FoundationContract.winmd").unwrap();
+        let table = &file.tables[TableIndex::TypeDef as usize];
+        let (column, size) = table.columns[1];
+        let offset = table.data + column;
+        assert_eq!(size, 2);
+        file.bytes[offset as usize..offset as usize + 2].fill(0xFF);
+        assert_eq!(file.validate(), Err(offset));
+    }
 }
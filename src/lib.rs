@@ -1,10 +1,14 @@
 //! A Windows Metadata (winmd) parser
+mod error;
 mod file;
 pub mod parsed;
+mod schema;
 mod traits;
 mod type_reader;
 
+pub use error::Error;
 pub use file::{File, TableIndex};
 pub use parsed::*;
+pub use schema::{CodedIndex, Column, ColumnKind};
 pub use traits::*;
 pub use type_reader::TypeReader;
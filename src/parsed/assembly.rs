@@ -0,0 +1,71 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct Assembly(pub Row);
+
+impl Assembly {
+    pub fn hash_algorithm(self, reader: &TypeReader) -> u32 {
+        reader.u32(self.0, 0)
+    }
+
+    pub fn version(self, reader: &TypeReader) -> Version {
+        Version::from_u64(reader.u64(self.0, 1))
+    }
+
+    pub fn flags(self, reader: &TypeReader) -> AssemblyFlags {
+        AssemblyFlags(reader.u32(self.0, 2))
+    }
+
+    pub fn public_key(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 3)
+    }
+
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 4)
+    }
+
+    pub fn culture(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 5)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::Assembly(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Default)]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub build: u16,
+    pub revision: u16,
+}
+
+impl Version {
+    pub(crate) fn from_u64(value: u64) -> Self {
+        Self {
+            major: value as u16,
+            minor: (value >> 16) as u16,
+            build: (value >> 32) as u16,
+            revision: (value >> 48) as u16,
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct AssemblyRef(pub Row);
+
+impl AssemblyRef {
+    pub fn version(self, reader: &TypeReader) -> Version {
+        Version::from_u64(reader.u64(self.0, 0))
+    }
+
+    pub fn flags(self, reader: &TypeReader) -> AssemblyFlags {
+        AssemblyFlags(reader.u32(self.0, 1))
+    }
+
+    pub fn public_key_or_token(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 2)
+    }
+
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 3)
+    }
+
+    pub fn culture(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 4)
+    }
+
+    pub fn hash_value(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 5)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::AssemblyRef(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
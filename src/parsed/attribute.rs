@@ -25,105 +25,427 @@ impl Attribute {
         }
     }
 
-    pub fn args(self, reader: &TypeReader) -> Vec<(String, AttributeArg)> {
-        let (mut sig, mut values) = match self.constructor(reader) {
-            AttributeType::MethodDef(method) => (reader.blob(method.0, 4), reader.blob(self.0, 2)),
-            AttributeType::MemberRef(method) => (reader.blob(method.0, 2), reader.blob(self.0, 2)),
+    pub fn args(self, reader: &TypeReader) -> Result<Vec<(String, AttributeArg)>, AttributeError> {
+        // The constructor's Param rows provide the names of the fixed arguments.
+        let sig = match self.constructor(reader) {
+            AttributeType::MethodDef(method) => method.signature(reader),
+            AttributeType::MemberRef(method) => match method.resolve(reader) {
+                Some(method) => method.signature(reader),
+                None => method.signature(reader),
+            },
         };
+        let mut values = reader.blob(self.0, 2);
 
-        let prolog = values.read_u16();
+        let prolog = values.try_read_u16().ok_or(AttributeError::Truncated)?;
         debug_assert!(prolog == 0x0001, "CustomAttribute Prolog must be 0x0001"); // Required by spec.
 
-        let _this_and_gen_param_count = sig.read_unsigned();
-        let fixed_arg_count = sig.read_unsigned();
-        let _ret_type = sig.read_unsigned();
-
-        let mut args: Vec<(String, AttributeArg)> = Vec::with_capacity(fixed_arg_count as usize);
-
-        for _ in 0..fixed_arg_count {
-            let arg = match ElementType::from_blob(&mut sig) {
-                ElementType::I8 => AttributeArg::I8(values.read_i8()),
-                ElementType::U8 => AttributeArg::U8(values.read_u8()),
-                ElementType::I16 => AttributeArg::I16(values.read_i16()),
-                ElementType::U16 => AttributeArg::U16(values.read_u16()),
-                ElementType::I32 => AttributeArg::I32(values.read_i32()),
-                ElementType::U32 => AttributeArg::U32(values.read_u32()),
-                ElementType::I64 => AttributeArg::I64(values.read_i64()),
-                ElementType::U64 => AttributeArg::U64(values.read_u64()),
-                ElementType::String => AttributeArg::String(values.read_str().to_string()),
-                ElementType::Struct(type_def_or_ref) | ElementType::Class(type_def_or_ref) => {
-                    let (namespace, type_name) = match type_def_or_ref {
-                        TypeDefOrRef::TypeDef(type_def) => type_def.name(reader),
-                        TypeDefOrRef::TypeRef(type_ref) => type_ref.name(reader),
-                        _ => panic!("Expected a TypeDef or TypeRef"),
-                    };
-
-                    if namespace == "System" && type_name == "Type" {
-                        let name = values.read_str();
-                        let index = name.rfind('.').unwrap();
-                        AttributeArg::TypeDef(
-                            reader.resolve_type_def((&name[0..index], &name[index + 1..])),
-                        )
-                    } else {
-                        let def = match type_def_or_ref {
-                            TypeDefOrRef::TypeRef(value) => {
-                                reader.resolve_type_def(value.name(reader))
-                            }
-                            TypeDefOrRef::TypeDef(value) => value,
-                            TypeDefOrRef::TypeSpec(_) => panic!("Unsupported underlying type"),
-                        };
-
-                        let underlying_type = def.underlying_type(reader);
-                        read_enum(&underlying_type, &mut values)
-                    }
-                }
-                _ => panic!("Unexpected fixed attribute argument type"),
-            };
+        let mut args: Vec<(String, AttributeArg)> = Vec::with_capacity(sig.params.len());
 
-            args.push((String::new(), arg));
+        for param in sig.params {
+            let name = match param.param {
+                Some(param) => param.name(reader).to_string(),
+                None => String::new(),
+            };
+            let arg_type = ArgType::from_element_type(reader, &param.sig_type.element_type)?;
+            args.push((name, arg_type.read(reader, &mut values)?));
         }
 
-        let named_arg_count = values.read_u16();
+        let named_arg_count = values.try_read_u16().ok_or(AttributeError::Truncated)?;
         args.reserve(named_arg_count as usize);
 
         for _ in 0..named_arg_count {
-            let id = values.read_u8();
+            let id = values.try_read_u8().ok_or(AttributeError::Truncated)?;
             debug_assert!(
                 id == 0x53 || id == 0x54,
                 "A NamedArg must start with an id of 0x53 (Field) or 0x54 (Property)"
             );
-            let arg_type = values.read_u8();
-            let name = values.read_str().to_string();
-            let arg = match arg_type {
-                0x02 => AttributeArg::Bool(values.read_u8() != 0),
-                0x08 => AttributeArg::I32(values.read_i32()),
-                0x0E => AttributeArg::String(values.read_str().to_string()),
-                0x50 => {
-                    let name = values.read_str();
-                    let index = name.rfind('.').unwrap();
-                    AttributeArg::TypeDef(
-                        reader.resolve_type_def((&name[0..index], &name[index + 1..])),
-                    )
+            let arg_type = ArgType::from_blob(reader, &mut values)?;
+            let name = values
+                .try_read_str()
+                .ok_or(AttributeError::Truncated)?
+                .to_string();
+            args.push((name, arg_type.read(reader, &mut values)?));
+        }
+
+        Ok(args)
+    }
+}
+
+/// An error encountered while decoding the arguments of a custom attribute.
+#[derive(Clone, PartialEq, Debug)]
+pub enum AttributeError {
+    /// A constructor parameter has a type that can't be used for an attribute argument.
+    UnsupportedType(ElementType),
+    /// The blob encodes an argument type with an unknown code.
+    InvalidTypeCode(u8),
+    /// An enum type is malformed or isn't defined in the reader's files.
+    TypeName(TypeNameError),
+    /// The type of an enum argument is not an enum with an integer underlying type.
+    InvalidEnum(TypeDef),
+    /// The blob ends before the arguments do, or holds a string that isn't valid UTF-8.
+    Truncated,
+}
+
+impl std::fmt::Display for AttributeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AttributeError::UnsupportedType(element_type) => {
+                write!(f, "Unsupported attribute argument type: {:?}", element_type)
+            }
+            AttributeError::InvalidTypeCode(code) => {
+                write!(f, "Invalid attribute argument type code: {:#x}", code)
+            }
+            AttributeError::TypeName(error) => error.fmt(f),
+            AttributeError::InvalidEnum(_) => write!(f, "Invalid enum attribute argument type"),
+            AttributeError::Truncated => write!(f, "Truncated attribute argument blob"),
+        }
+    }
+}
+
+impl std::error::Error for AttributeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AttributeError::TypeName(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl AttributeArg {
+    /// The name of the enum field matching an enum argument's value, such as `Agile` for
+    /// `MarshalingType.Agile`. Returns `None` for other arguments and for combined flags.
+    pub fn enum_name<'a>(&self, reader: &'a TypeReader) -> Option<&'a str> {
+        match self {
+            AttributeArg::Enum(def, value) => def
+                .fields(reader)
+                .find(|field| {
+                    field
+                        .constants(reader)
+                        .any(|constant| constant.decode(reader) == *value)
+                })
+                .map(|field| field.name(reader)),
+            _ => None,
+        }
+    }
+}
+
+/// The type of a custom attribute argument, either taken from the constructor's signature or
+/// encoded as a FieldOrPropType in the blob itself (ECMA-335 II.23.3).
+enum ArgType {
+    Primitive(ElementType),
+    Type,
+    Boxed,
+    Enum(TypeDef),
+    Array(Box<ArgType>),
+}
+
+impl ArgType {
+    fn from_element_type(
+        reader: &TypeReader,
+        element_type: &ElementType,
+    ) -> Result<Self, AttributeError> {
+        match element_type {
+            ElementType::Object => Ok(ArgType::Boxed),
+            ElementType::SZArray(_, element_type) => Ok(ArgType::Array(Box::new(
+                ArgType::from_element_type(reader, element_type)?,
+            ))),
+            ElementType::Struct(type_def_or_ref) | ElementType::Class(type_def_or_ref) => {
+                match type_def_or_ref.name(reader) {
+                    Some(("System", "Type")) => return Ok(ArgType::Type),
+                    Some(("System", "Object")) => return Ok(ArgType::Boxed),
+                    _ => {}
                 }
-                _ => panic!("Unexpected named attribute argument type"),
-            };
-            args.push((name, arg));
+
+                // Any other named type must be an enum.
+                match type_def_or_ref {
+                    TypeDefOrRef::TypeDef(def) => Ok(ArgType::Enum(*def)),
+                    TypeDefOrRef::TypeRef(type_ref) => match type_ref.find(reader) {
+                        Some(def) => Ok(ArgType::Enum(def)),
+                        None => {
+                            let (namespace, name) = type_ref.name(reader);
+                            Err(AttributeError::TypeName(TypeNameError::NotFound(format!(
+                                "{}.{}",
+                                namespace, name
+                            ))))
+                        }
+                    },
+                    TypeDefOrRef::TypeSpec(_) => {
+                        Err(AttributeError::UnsupportedType(element_type.clone()))
+                    }
+                }
+            }
+            ElementType::Bool
+            | ElementType::Char
+            | ElementType::I8
+            | ElementType::U8
+            | ElementType::I16
+            | ElementType::U16
+            | ElementType::I32
+            | ElementType::U32
+            | ElementType::I64
+            | ElementType::U64
+            | ElementType::F32
+            | ElementType::F64
+            | ElementType::String => Ok(ArgType::Primitive(element_type.clone())),
+            _ => Err(AttributeError::UnsupportedType(element_type.clone())),
+        }
+    }
+
+    fn from_blob(reader: &TypeReader, blob: &mut Blob) -> Result<Self, AttributeError> {
+        match blob.try_read_u8().ok_or(AttributeError::Truncated)? {
+            0x50 => Ok(ArgType::Type),
+            0x51 => Ok(ArgType::Boxed),
+            0x1D => Ok(ArgType::Array(Box::new(ArgType::from_blob(reader, blob)?))),
+            0x55 => TypeName::parse(blob.try_read_str().ok_or(AttributeError::Truncated)?)
+                .and_then(|type_name| type_name.resolve(reader))
+                .map(ArgType::Enum)
+                .map_err(AttributeError::TypeName),
+            code @ 0x02..=0x0e => Ok(ArgType::Primitive(ElementType::from_code(code as u32))),
+            code => Err(AttributeError::InvalidTypeCode(code)),
+        }
+    }
+
+    fn read(&self, reader: &TypeReader, blob: &mut Blob) -> Result<AttributeArg, AttributeError> {
+        match self {
+            ArgType::Primitive(element_type) => read_primitive(element_type, blob),
+            ArgType::Type => match read_ser_string(blob)? {
+                Some(name) => read_type(reader, name),
+                None => Ok(AttributeArg::Null),
+            },
+            ArgType::Boxed => ArgType::from_blob(reader, blob)?.read(reader, blob),
+            ArgType::Enum(def) => {
+                // The single instance field of an enum has the enum's underlying type.
+                let underlying_type = def
+                    .fields(reader)
+                    .find(|field| !field.flags(reader).is_static())
+                    .map(|field| field.signature(reader).element_type)
+                    .ok_or(AttributeError::InvalidEnum(*def))?;
+
+                match read_enum(&underlying_type, blob) {
+                    Some(value) => Ok(AttributeArg::Enum(*def, value?)),
+                    None => Err(AttributeError::InvalidEnum(*def)),
+                }
+            }
+            ArgType::Array(element_type) => {
+                match blob.try_read_u32().ok_or(AttributeError::Truncated)? {
+                    0xFFFF_FFFF => Ok(AttributeArg::Null),
+                    len => Ok(AttributeArg::Array(
+                        (0..len)
+                            .map(|_| element_type.read(reader, blob))
+                            .collect::<Result<_, _>>()?,
+                    )),
+                }
+            }
         }
+    }
+}
+
+/// Reads an enum value of the given underlying type, or returns `None` if the type isn't an
+/// integer type.
+fn read_enum(
+    element_type: &ElementType,
+    blob: &mut Blob,
+) -> Option<Result<ConstantValue, AttributeError>> {
+    let value = match element_type {
+        ElementType::I8 => blob.try_read_i8().map(ConstantValue::I8),
+        ElementType::U8 => blob.try_read_u8().map(ConstantValue::U8),
+        ElementType::I16 => blob.try_read_i16().map(ConstantValue::I16),
+        ElementType::U16 => blob.try_read_u16().map(ConstantValue::U16),
+        ElementType::I32 => blob.try_read_i32().map(ConstantValue::I32),
+        ElementType::U32 => blob.try_read_u32().map(ConstantValue::U32),
+        ElementType::I64 => blob.try_read_i64().map(ConstantValue::I64),
+        ElementType::U64 => blob.try_read_u64().map(ConstantValue::U64),
+        _ => return None,
+    };
+
+    Some(value.ok_or(AttributeError::Truncated))
+}
+
+fn read_primitive(
+    element_type: &ElementType,
+    blob: &mut Blob,
+) -> Result<AttributeArg, AttributeError> {
+    let value = match element_type {
+        ElementType::Bool => blob
+            .try_read_u8()
+            .map(|value| AttributeArg::Bool(value != 0)),
+        ElementType::Char => blob.try_read_char().map(AttributeArg::Char),
+        ElementType::I8 => blob.try_read_i8().map(AttributeArg::I8),
+        ElementType::U8 => blob.try_read_u8().map(AttributeArg::U8),
+        ElementType::I16 => blob.try_read_i16().map(AttributeArg::I16),
+        ElementType::U16 => blob.try_read_u16().map(AttributeArg::U16),
+        ElementType::I32 => blob.try_read_i32().map(AttributeArg::I32),
+        ElementType::U32 => blob.try_read_u32().map(AttributeArg::U32),
+        ElementType::I64 => blob.try_read_i64().map(AttributeArg::I64),
+        ElementType::U64 => blob.try_read_u64().map(AttributeArg::U64),
+        ElementType::F32 => blob.try_read_f32().map(AttributeArg::F32),
+        ElementType::F64 => blob.try_read_f64().map(AttributeArg::F64),
+        ElementType::String => {
+            return Ok(match read_ser_string(blob)? {
+                Some(value) => AttributeArg::String(value.to_string()),
+                None => AttributeArg::Null,
+            })
+        }
+        _ => return Err(AttributeError::UnsupportedType(element_type.clone())),
+    };
+
+    value.ok_or(AttributeError::Truncated)
+}
+
+fn read_ser_string<'a>(blob: &mut Blob<'a>) -> Result<Option<&'a str>, AttributeError> {
+    blob.try_read_ser_string().ok_or(AttributeError::Truncated)
+}
+
+/// Resolves a serialized System.Type name, keeping the parsed name when it isn't a type
+/// definition known to the reader, such as a generic instantiation or an array.
+fn read_type(reader: &TypeReader, name: &str) -> Result<AttributeArg, AttributeError> {
+    let type_name = TypeName::parse(name).map_err(AttributeError::TypeName)?;
 
-        args
+    if type_name.generic_args.is_empty() && type_name.suffixes.is_empty() {
+        if let Ok(def) = type_name.resolve(reader) {
+            return Ok(AttributeArg::TypeDef(def));
+        }
     }
+
+    Ok(AttributeArg::TypeName(type_name))
 }
 
-fn read_enum(element_type: &ElementType, blob: &mut Blob) -> AttributeArg {
-    match element_type {
-        ElementType::I8 => AttributeArg::I8(blob.read_i8()),
-        ElementType::U8 => AttributeArg::U8(blob.read_u8()),
-        ElementType::I16 => AttributeArg::I16(blob.read_i16()),
-        ElementType::U16 => AttributeArg::U16(blob.read_u16()),
-        ElementType::I32 => AttributeArg::I32(blob.read_i32()),
-        ElementType::U32 => AttributeArg::U32(blob.read_u32()),
-        ElementType::I64 => AttributeArg::I64(blob.read_i64()),
-        ElementType::U64 => AttributeArg::U64(blob.read_u64()),
-        _ => panic!("Invalid underlying enum type encountered!"),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+
+    // Loads the test metadata along with a second file holding the given blob.
+    fn load(blob: &[u8]) -> TypeReader {
+        let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+        let mut reader = TypeReader::from_iter(vec![path]);
+        let mut file = TestFile::new();
+        let blob = file.blob(blob);
+        file.table(TableIndex::StandaloneSig, &[&[blob]]);
+        reader.insert_test_file(file.build());
+        reader
+    }
+
+    fn test_blob(reader: &TypeReader) -> Blob<'_> {
+        reader.blob(Row::new(0, TableIndex::StandaloneSig, 1), 0)
+    }
+
+    #[test]
+    fn errors() {
+        let reader = &load(&[0x99]);
+        let mut blob = test_blob(reader);
+        assert!(
+            ArgType::from_blob(reader, &mut blob).err()
+                == Some(AttributeError::InvalidTypeCode(0x99))
+        );
+
+        let element_type = ElementType::Var(0);
+        assert!(
+            ArgType::from_element_type(reader, &element_type).err()
+                == Some(AttributeError::UnsupportedType(element_type))
+        );
+
+        let name = b"System.AttributeTargets";
+        let mut bytes = vec![0x55, name.len() as u8];
+        bytes.extend(name);
+        let reader = &load(&bytes);
+        let mut blob = test_blob(reader);
+        assert!(
+            ArgType::from_blob(reader, &mut blob).err()
+                == Some(AttributeError::TypeName(TypeNameError::NotFound(
+                    "System.AttributeTargets".to_string()
+                )))
+        );
+
+        let point = reader.resolve_type_def(("Windows.Foundation", "Point"));
+        let mut blob = test_blob(reader);
+        assert!(
+            ArgType::Enum(point).read(reader, &mut blob).err()
+                == Some(AttributeError::InvalidEnum(point))
+        );
+    }
+
+    #[test]
+    fn type_names() {
+        let read = |name: &str| {
+            let mut bytes = vec![name.len() as u8];
+            bytes.extend(name.as_bytes());
+            let reader = &load(&bytes);
+            ArgType::Type.read(reader, &mut test_blob(reader))
+        };
+
+        let reader = &load(&[]);
+        let point = reader.resolve_type_def(("Windows.Foundation", "Point"));
+        assert!(read("Windows.Foundation.Point") == Ok(AttributeArg::TypeDef(point)));
+
+        let name = "Windows.Foundation.IReference`1[[Windows.Foundation.Point]]";
+        assert!(read(name) == Ok(AttributeArg::TypeName(TypeName::parse(name).unwrap())));
+
+        let name = "Windows.Foundation.Point[]";
+        assert!(read(name) == Ok(AttributeArg::TypeName(TypeName::parse(name).unwrap())));
+
+        let name = "Test.Missing";
+        assert!(read(name) == Ok(AttributeArg::TypeName(TypeName::parse(name).unwrap())));
+
+        assert!(matches!(
+            read("Test.Missing["),
+            Err(AttributeError::TypeName(TypeNameError::Syntax(..)))
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        let mut file = TestFile::new();
+        let namespace = file.string("Test");
+        let class = file.string("SizeAttribute");
+        let constructor = file.string(".ctor");
+        let count = file.string("count");
+        let name = file.string("name");
+
+        // SizeAttribute(int32 count, string name)
+        let sig = file.blob(&[0x20, 0x02, 0x01, 0x08, 0x0e]);
+        file.table(TableIndex::TypeDef, &[&[0, class, namespace, 0, 1, 1]]);
+        file.table(
+            TableIndex::MethodDef,
+            &[&[0, 0, 0x1886, constructor, sig, 1]],
+        );
+        file.table(TableIndex::Param, &[&[0, 1, count], &[0, 2, name]]);
+
+        let def = TypeDef(Row::new(0, TableIndex::TypeDef, 0));
+        let method = MethodDef(Row::new(0, TableIndex::MethodDef, 0));
+        let parent = HasAttribute::TypeDef(def).encode();
+        let attribute_type = AttributeType::MethodDef(method).encode();
+
+        // [SizeAttribute(42, "abc", Size = 7)] followed by every shorter prefix of its blob.
+        let mut value = vec![0x01, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x03];
+        value.extend(b"abc");
+        value.extend(&[0x01, 0x00, 0x54, 0x08, 0x04]);
+        value.extend(b"Size");
+        value.extend(&[0x07, 0x00, 0x00, 0x00]);
+
+        let rows: Vec<Vec<u32>> = (0..=value.len())
+            .rev()
+            .map(|len| vec![parent, attribute_type, file.blob(&value[..len])])
+            .collect();
+        let rows: Vec<&[u32]> = rows.iter().map(|row| &row[..]).collect();
+        file.table(TableIndex::CustomAttribute, &rows);
+        let reader = &file.reader();
+
+        let attributes: Vec<Attribute> = def.attributes(reader).collect();
+        assert_eq!(attributes.len(), value.len() + 1);
+
+        assert!(
+            attributes[0].args(reader)
+                == Ok(vec![
+                    ("count".to_string(), AttributeArg::I32(42)),
+                    ("name".to_string(), AttributeArg::String("abc".to_string())),
+                    ("Size".to_string(), AttributeArg::I32(7)),
+                ])
+        );
+
+        for attribute in &attributes[1..] {
+            assert!(attribute.args(reader) == Err(AttributeError::Truncated));
+        }
     }
 }
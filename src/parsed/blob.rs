@@ -2,40 +2,58 @@ use crate::*;
 
 use std::convert::TryInto;
 
+const TRUNCATED: &str = "Blob ended before the value being read";
+
 pub struct Blob<'a> {
     pub reader: &'a TypeReader,
     pub file_index: u16,
     offset: usize,
+    end: usize,
 }
 
 impl<'a> Blob<'a> {
-    pub fn new(reader: &'a TypeReader, file_index: u16, offset: usize) -> Self {
+    pub fn new(reader: &'a TypeReader, file_index: u16, offset: usize, len: usize) -> Self {
         Blob {
             reader,
             file_index,
             offset,
+            end: offset + len,
         }
     }
 
     fn bytes(&self) -> &[u8] {
-        &self.file().bytes[self.offset..]
+        &self.file().bytes[self.offset..self.end]
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset >= self.end
     }
 
     pub fn peek_unsigned(&self) -> (u32, usize) {
+        self.try_peek_unsigned().expect(TRUNCATED)
+    }
+
+    fn try_peek_unsigned(&self) -> Option<(u32, usize)> {
         let bytes = self.bytes();
+        let first = *bytes.first()?;
 
-        if bytes[0] & 0x80 == 0 {
-            (bytes[0] as u32, 1)
-        } else if bytes[0] & 0xC0 == 0x80 {
-            ((((bytes[0] & 0x3F) as u32) << 8) | bytes[1] as u32, 2)
+        if first & 0x80 == 0 {
+            Some((first as u32, 1))
+        } else if first & 0xC0 == 0x80 {
+            Some(((((first & 0x3F) as u32) << 8) | *bytes.get(1)? as u32, 2))
         } else {
-            (
-                (((bytes[0] & 0x1F) as u32) << 24)
-                    | (bytes[1] as u32) << 16
-                    | (bytes[2] as u32) << 8
-                    | bytes[3] as u32,
+            let rest = bytes.get(1..4)?;
+            Some((
+                (((first & 0x1F) as u32) << 24)
+                    | (rest[0] as u32) << 16
+                    | (rest[1] as u32) << 8
+                    | rest[2] as u32,
                 4,
-            )
+            ))
         }
     }
 
@@ -45,6 +63,31 @@ impl<'a> Blob<'a> {
         value
     }
 
+    /// Like [`Blob::read_unsigned`] but returns `None` if the blob ends first.
+    pub fn try_read_unsigned(&mut self) -> Option<u32> {
+        let (value, offset) = self.try_peek_unsigned()?;
+        self.offset += offset;
+        Some(value)
+    }
+
+    pub fn read_signed(&mut self) -> i32 {
+        let (value, offset) = self.peek_unsigned();
+        self.offset += offset;
+
+        // The sign bit is rotated into the least significant bit of the encoded value.
+        let bits = match offset {
+            1 => 7,
+            2 => 14,
+            _ => 29,
+        };
+
+        if value & 1 == 0 {
+            (value >> 1) as i32
+        } else {
+            (value >> 1) as i32 - (1 << (bits - 1))
+        }
+    }
+
     pub fn read_expected(&mut self, expected: u32) -> bool {
         let (value, offset) = self.peek_unsigned();
         if value == expected {
@@ -55,7 +98,7 @@ impl<'a> Blob<'a> {
         }
     }
 
-    pub fn read_modifiers(&mut self) -> Vec<TypeDefOrRef> {
+    pub fn read_modifiers(&mut self) -> Vec<CustomModifier> {
         let mut mods = vec![];
 
         loop {
@@ -64,7 +107,10 @@ impl<'a> Blob<'a> {
                 break;
             } else {
                 self.offset += offset;
-                mods.push(TypeDefOrRef::decode(self.read_unsigned(), self.file_index))
+                mods.push(CustomModifier {
+                    required: value == 31,
+                    modifier_type: TypeDefOrRef::decode(self.read_unsigned(), self.file_index),
+                })
             }
         }
 
@@ -79,52 +125,146 @@ impl<'a> Blob<'a> {
         std::str::from_utf8(&self.file().bytes[self.offset - len..self.offset]).unwrap()
     }
 
+    /// Like [`Blob::read_str`] but returns `None` if the blob ends first or the string isn't
+    /// valid UTF-8.
+    pub fn try_read_str(&mut self) -> Option<&'a str> {
+        let len = self.try_read_unsigned()? as usize;
+        let reader = self.reader;
+        let bytes = &reader.files[self.file_index as usize].bytes[self.offset..self.end];
+        let value = std::str::from_utf8(bytes.get(..len)?).ok()?;
+        self.offset += len;
+        Some(value)
+    }
+
+    /// Reads a SerString, which is a string or a single 0xFF byte for a null string.
+    pub fn read_ser_string(&mut self) -> Option<&str> {
+        if self.bytes()[0] == 0xFF {
+            self.offset += 1;
+            None
+        } else {
+            Some(self.read_str())
+        }
+    }
+
+    /// Like [`Blob::read_ser_string`] but returns `None` if the blob ends first or the string
+    /// isn't valid UTF-8, and `Some(None)` for a null string.
+    pub fn try_read_ser_string(&mut self) -> Option<Option<&'a str>> {
+        if *self.bytes().first()? == 0xFF {
+            self.offset += 1;
+            Some(None)
+        } else {
+            self.try_read_str().map(Some)
+        }
+    }
+
     pub fn read_i8(&mut self) -> i8 {
-        let value = i8::from_le_bytes(self.bytes()[..1].try_into().unwrap());
-        self.offset += 1;
-        value
+        self.try_read_i8().expect(TRUNCATED)
     }
 
     pub fn read_u8(&mut self) -> u8 {
-        let value = u8::from_le_bytes(self.bytes()[..1].try_into().unwrap());
-        self.offset += 1;
-        value
+        self.try_read_u8().expect(TRUNCATED)
     }
 
     pub fn read_i16(&mut self) -> i16 {
-        let value = i16::from_le_bytes(self.bytes()[..2].try_into().unwrap());
-        self.offset += 2;
-        value
+        self.try_read_i16().expect(TRUNCATED)
     }
 
     pub fn read_u16(&mut self) -> u16 {
-        let value = u16::from_le_bytes(self.bytes()[..2].try_into().unwrap());
-        self.offset += 2;
-        value
+        self.try_read_u16().expect(TRUNCATED)
+    }
+
+    /// Reads a UTF-16 code unit, replacing an unpaired surrogate with U+FFFD.
+    pub fn read_char(&mut self) -> char {
+        self.try_read_char().expect(TRUNCATED)
     }
 
     pub fn read_i32(&mut self) -> i32 {
-        let value = i32::from_le_bytes(self.bytes()[..4].try_into().unwrap());
-        self.offset += 4;
-        value
+        self.try_read_i32().expect(TRUNCATED)
     }
 
     pub fn read_u32(&mut self) -> u32 {
-        let value = u32::from_le_bytes(self.bytes()[..4].try_into().unwrap());
-        self.offset += 4;
-        value
+        self.try_read_u32().expect(TRUNCATED)
     }
 
     pub fn read_i64(&mut self) -> i64 {
-        let value = i64::from_le_bytes(self.bytes()[..8].try_into().unwrap());
-        self.offset += 8;
-        value
+        self.try_read_i64().expect(TRUNCATED)
     }
 
     pub fn read_u64(&mut self) -> u64 {
-        let value = u64::from_le_bytes(self.bytes()[..8].try_into().unwrap());
-        self.offset += 8;
-        value
+        self.try_read_u64().expect(TRUNCATED)
+    }
+
+    pub fn read_f32(&mut self) -> f32 {
+        self.try_read_f32().expect(TRUNCATED)
+    }
+
+    pub fn read_f64(&mut self) -> f64 {
+        self.try_read_f64().expect(TRUNCATED)
+    }
+
+    // The `try_read_*` methods below return `None` rather than panicking if the blob ends
+    // before the value does.
+
+    pub fn try_read_i8(&mut self) -> Option<i8> {
+        self.try_read_bytes().map(i8::from_le_bytes)
+    }
+
+    pub fn try_read_u8(&mut self) -> Option<u8> {
+        self.try_read_bytes().map(u8::from_le_bytes)
+    }
+
+    pub fn try_read_i16(&mut self) -> Option<i16> {
+        self.try_read_bytes().map(i16::from_le_bytes)
+    }
+
+    pub fn try_read_u16(&mut self) -> Option<u16> {
+        self.try_read_bytes().map(u16::from_le_bytes)
+    }
+
+    pub fn try_read_char(&mut self) -> Option<char> {
+        self.try_read_u16().map(|value| {
+            std::char::from_u32(value as u32).unwrap_or(std::char::REPLACEMENT_CHARACTER)
+        })
+    }
+
+    pub fn try_read_i32(&mut self) -> Option<i32> {
+        self.try_read_bytes().map(i32::from_le_bytes)
+    }
+
+    pub fn try_read_u32(&mut self) -> Option<u32> {
+        self.try_read_bytes().map(u32::from_le_bytes)
+    }
+
+    pub fn try_read_i64(&mut self) -> Option<i64> {
+        self.try_read_bytes().map(i64::from_le_bytes)
+    }
+
+    pub fn try_read_u64(&mut self) -> Option<u64> {
+        self.try_read_bytes().map(u64::from_le_bytes)
+    }
+
+    pub fn try_read_f32(&mut self) -> Option<f32> {
+        self.try_read_bytes().map(f32::from_le_bytes)
+    }
+
+    pub fn try_read_f64(&mut self) -> Option<f64> {
+        self.try_read_bytes().map(f64::from_le_bytes)
+    }
+
+    fn try_read_bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes = self.bytes().get(..N)?.try_into().ok()?;
+        self.offset += N;
+        Some(bytes)
+    }
+
+    pub fn read_utf16(&mut self) -> String {
+        let units: Vec<u16> = self
+            .bytes()
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect();
+        self.offset += units.len() * 2;
+        String::from_utf16_lossy(&units)
     }
 
     fn file(&self) -> &File {
@@ -0,0 +1,81 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct ClassLayout(pub Row);
+
+impl ClassLayout {
+    pub fn packing_size(self, reader: &TypeReader) -> u32 {
+        reader.u32(self.0, 0)
+    }
+
+    pub fn class_size(self, reader: &TypeReader) -> u32 {
+        reader.u32(self.0, 1)
+    }
+
+    pub fn parent(self, reader: &TypeReader) -> TypeDef {
+        TypeDef(Row::new(
+            reader.u32(self.0, 2) - 1,
+            TableIndex::TypeDef,
+            self.0.file_index,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn explicit_layout() {
+        let mut file = TestFile::new();
+        let namespace = file.string("Test");
+        let explicit = file.string("Explicit");
+        let packed = file.string("Packed");
+        let plain = file.string("Plain");
+        let value = file.string("value");
+
+        // An explicit-layout struct with two fields, a sequential struct packed to one byte and a
+        // sequential struct without a layout row.
+        file.table(
+            TableIndex::TypeDef,
+            &[
+                &[0x10, explicit, namespace, 0, 1, 1],
+                &[0x08, packed, namespace, 0, 3, 1],
+                &[0x08, plain, namespace, 0, 4, 1],
+            ],
+        );
+        file.table(
+            TableIndex::Field,
+            &[
+                &[0, value, 0],
+                &[0, value, 0],
+                &[0, value, 0],
+                &[0, value, 0],
+            ],
+        );
+        file.table(TableIndex::ClassLayout, &[&[8, 16, 1], &[1, 0, 2]]);
+        file.table(TableIndex::FieldLayout, &[&[0, 1], &[4, 2]]);
+        let reader = &file.reader();
+
+        let def = |row| TypeDef(Row::new(row, TableIndex::TypeDef, 0));
+        let (explicit, packed, plain) = (def(0), def(1), def(2));
+
+        let layout = explicit.class_layout(reader).unwrap();
+        assert_eq!(layout.packing_size(reader), 8);
+        assert_eq!(layout.class_size(reader), 16);
+        assert_eq!(layout.parent(reader), explicit);
+        let offsets: Vec<Option<u32>> = explicit.fields(reader).map(|f| f.offset(reader)).collect();
+        assert_eq!(offsets, [Some(0), Some(4)]);
+
+        let layout = packed.class_layout(reader).unwrap();
+        assert_eq!(layout.packing_size(reader), 1);
+        assert_eq!(layout.class_size(reader), 0);
+        assert_eq!(layout.parent(reader), packed);
+        assert_eq!(packed.fields(reader).next().unwrap().offset(reader), None);
+
+        assert_eq!(plain.class_layout(reader), None);
+        assert_eq!(plain.fields(reader).next().unwrap().offset(reader), None);
+    }
+}
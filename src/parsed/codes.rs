@@ -25,15 +25,30 @@ pub enum HasAttribute {
     Param,
     InterfaceImpl,
     MemberRef,
-    TypeSpec = 13,
+    Module,
+    Property = 9,
+    Event,
+    ModuleRef = 12,
+    TypeSpec,
+    Assembly,
+    AssemblyRef,
     GenericParam = 19,
+    GenericParamConstraint,
+    MethodSpec,
+}
+
+#[type_code(1)]
+pub enum HasFieldMarshal {
+    Field,
+    Param,
 }
 
 #[type_code(3)]
 pub enum MemberRefParent {
     TypeDef,
     TypeRef,
-    MethodDef = 3,
+    ModuleRef,
+    MethodDef,
     TypeSpec,
 }
 
@@ -41,6 +56,33 @@ pub enum MemberRefParent {
 pub enum HasConstant {
     Field,
     Param,
+    Property,
+}
+
+#[type_code(1)]
+pub enum MemberForwarded {
+    Field,
+    MethodDef,
+}
+
+#[type_code(1)]
+pub enum HasSemantics {
+    Event,
+    Property,
+}
+
+#[type_code(1)]
+pub enum MethodDefOrRef {
+    MethodDef,
+    MemberRef,
+}
+
+#[type_code(2)]
+pub enum ResolutionScope {
+    Module,
+    ModuleRef,
+    AssemblyRef,
+    TypeRef,
 }
 
 #[type_code(3)]
@@ -50,19 +92,66 @@ pub enum AttributeType {
 }
 
 impl TypeDefOrRef {
-    pub fn name<'a>(&self, reader: &'a TypeReader) -> (&'a str, &'a str) {
+    /// The name of the type, or of its generic type definition for a generic instantiation.
+    /// TypeSpecs that don't name a type, such as arrays, have no name.
+    pub fn name<'a>(&self, reader: &'a TypeReader) -> Option<(&'a str, &'a str)> {
         match self {
-            TypeDefOrRef::TypeDef(value) => value.name(reader),
-            TypeDefOrRef::TypeRef(value) => value.name(reader),
-            TypeDefOrRef::TypeSpec(_) => panic!("Expected a TypeDef or TypeRef"),
+            TypeDefOrRef::TypeDef(value) => Some(value.name(reader)),
+            TypeDefOrRef::TypeRef(value) => Some(value.name(reader)),
+            TypeDefOrRef::TypeSpec(value) => value.definition(reader)?.name(reader),
         }
     }
 
-    pub fn resolve(&self, reader: &TypeReader) -> TypeDef {
+    /// Resolves the type, or the generic type definition for a generic instantiation.
+    /// Types that aren't defined in the reader's files and TypeSpecs that don't name a type,
+    /// such as arrays, resolve to `None`.
+    pub fn resolve(&self, reader: &TypeReader) -> Option<TypeDef> {
         match self {
-            Self::TypeDef(value) => *value,
-            Self::TypeRef(value) => value.resolve(reader),
-            Self::TypeSpec(_) => panic!("Expected a TypeDef or TypeRef"),
+            Self::TypeDef(value) => Some(*value),
+            Self::TypeRef(value) => value.find(reader),
+            Self::TypeSpec(value) => value.definition(reader)?.resolve(reader),
         }
     }
+
+    /// The type arguments of a generic instantiation, or an empty list for any other type.
+    pub fn generic_args(&self, reader: &TypeReader) -> Vec<ElementType> {
+        match self {
+            Self::TypeSpec(value) => value
+                .decode(reader)
+                .map(|instance| instance.args)
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn resolve_missing_type() {
+        let mut file = TestFile::new();
+        let module = file.string("Test.winmd");
+        let namespace = file.string("Test");
+        let local = file.string("Local");
+        let system = file.string("System");
+        let attribute = file.string("Attribute");
+        file.table(TableIndex::Module, &[&[0, module, 0, 0, 0]]);
+        file.table(TableIndex::TypeDef, &[&[0, local, namespace, 0, 1, 1]]);
+
+        let scope = ResolutionScope::Module(Module(Row::new(0, TableIndex::Module, 0))).encode();
+        file.table(
+            TableIndex::TypeRef,
+            &[&[scope, local, namespace], &[scope, attribute, system]],
+        );
+        let reader = &file.reader();
+
+        let local = TypeDef(Row::new(0, TableIndex::TypeDef, 0));
+        let type_ref = |row| TypeDefOrRef::TypeRef(TypeRef(Row::new(row, TableIndex::TypeRef, 0)));
+        assert_eq!(type_ref(0).resolve(reader), Some(local));
+        assert_eq!(type_ref(1).resolve(reader), None);
+        assert_eq!(type_ref(1).name(reader), Some(("System", "Attribute")));
+    }
 }
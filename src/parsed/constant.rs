@@ -4,12 +4,98 @@ use crate::TypeReader;
 #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub struct Constant(pub Row);
 
+#[derive(Clone, PartialEq, Debug)]
+pub enum ConstantValue {
+    Bool(bool),
+    Char(char),
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Null,
+}
+
 impl Constant {
+    /// The type of the constant. A null reference constant has the type `Object`.
     pub fn value_type(self, reader: &TypeReader) -> ElementType {
-        ElementType::from_code(reader.u32(self.0, 0))
+        match reader.u32(self.0, 0) {
+            0x12 => ElementType::Object,
+            code => ElementType::from_code(code),
+        }
     }
 
-    pub fn value(self, reader: &TypeReader) -> Blob {
+    pub fn value(self, reader: &TypeReader) -> Blob<'_> {
         reader.blob(self.0, 2)
     }
+
+    pub fn decode(self, reader: &TypeReader) -> ConstantValue {
+        let mut value = self.value(reader);
+
+        match reader.u32(self.0, 0) {
+            0x02 => ConstantValue::Bool(value.read_u8() != 0),
+            0x03 => ConstantValue::Char(value.read_char()),
+            0x04 => ConstantValue::I8(value.read_i8()),
+            0x05 => ConstantValue::U8(value.read_u8()),
+            0x06 => ConstantValue::I16(value.read_i16()),
+            0x07 => ConstantValue::U16(value.read_u16()),
+            0x08 => ConstantValue::I32(value.read_i32()),
+            0x09 => ConstantValue::U32(value.read_u32()),
+            0x0a => ConstantValue::I64(value.read_i64()),
+            0x0b => ConstantValue::U64(value.read_u64()),
+            0x0c => ConstantValue::F32(value.read_f32()),
+            0x0d => ConstantValue::F64(value.read_f64()),
+            0x0e => ConstantValue::String(value.read_utf16()),
+            0x12 => ConstantValue::Null,
+            unknown_type => panic!("Unexpected constant type: {:x}", unknown_type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+
+    #[test]
+    fn decode() {
+        let mut file = TestFile::new();
+        let string = file.blob(&[b'H', 0, b'i', 0]);
+        let null = file.blob(&[0, 0, 0, 0]);
+        let char = file.blob(&[b'A', 0]);
+        let surrogate = file.blob(&[0x00, 0xD8]);
+        let parent = HasConstant::Field(Field(Row::new(0, TableIndex::Field, 0))).encode();
+        file.table(TableIndex::Field, &[&[0, 0, 0]]);
+        file.table(
+            TableIndex::Constant,
+            &[
+                &[0x0e, parent, string],
+                &[0x12, parent, null],
+                &[0x03, parent, char],
+                &[0x03, parent, surrogate],
+            ],
+        );
+        let reader = &file.reader();
+
+        let constants: Vec<Constant> = reader.rows(0, TableIndex::Constant).map(Constant).collect();
+        assert_eq!(
+            constants[0].decode(reader),
+            ConstantValue::String("Hi".to_string())
+        );
+        assert_eq!(constants[0].value_type(reader), ElementType::String);
+        assert_eq!(constants[1].decode(reader), ConstantValue::Null);
+        assert_eq!(constants[1].value_type(reader), ElementType::Object);
+        assert_eq!(constants[2].decode(reader), ConstantValue::Char('A'));
+        assert_eq!(
+            constants[3].decode(reader),
+            ConstantValue::Char(std::char::REPLACEMENT_CHARACTER)
+        );
+    }
 }
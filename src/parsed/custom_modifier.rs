@@ -0,0 +1,82 @@
+use super::*;
+use crate::TypeReader;
+
+/// A `modreq` (required) or `modopt` (optional) custom modifier applied to a type in a signature.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CustomModifier {
+    pub required: bool,
+    pub modifier_type: TypeDefOrRef,
+}
+
+impl CustomModifier {
+    pub fn name<'a>(&self, reader: &'a TypeReader) -> Option<(&'a str, &'a str)> {
+        self.modifier_type.name(reader)
+    }
+
+    pub fn is_const(&self, reader: &TypeReader) -> bool {
+        self.name(reader) == Some(("System.Runtime.CompilerServices", "IsConst"))
+    }
+
+    pub fn is_volatile(&self, reader: &TypeReader) -> bool {
+        self.name(reader) == Some(("System.Runtime.CompilerServices", "IsVolatile"))
+    }
+
+    pub fn is_by_value(&self, reader: &TypeReader) -> bool {
+        self.name(reader) == Some(("System.Runtime.CompilerServices", "IsByValue"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+
+    #[test]
+    fn modifiers_in_order() {
+        let mut file = TestFile::new();
+        let namespace = file.string("System.Runtime.CompilerServices");
+        let is_const = file.string("IsConst");
+        let is_volatile = file.string("IsVolatile");
+        file.table(
+            TableIndex::TypeRef,
+            &[&[0, is_const, namespace], &[0, is_volatile, namespace]],
+        );
+
+        // modreq(IsConst) modopt(IsVolatile) int32, then a pointer to modopt(IsConst) int32.
+        let sig = file.blob(&[0x1f, 0x05, 0x20, 0x09, 0x08, 0x0f, 0x20, 0x05, 0x08]);
+        file.table(TableIndex::TypeSpec, &[&[sig]]);
+
+        let reader = &file.reader();
+        let mut blob = reader.blob(Row::new(0, TableIndex::TypeSpec, 0), 0);
+        let first = SignatureType::from_blob(&mut blob);
+        assert_eq!(first.modifiers.len(), 2);
+        assert!(first.modifiers[0].required);
+        assert!(first.modifiers[0].is_const(reader));
+        assert!(!first.modifiers[1].required);
+        assert!(first.modifiers[1].is_volatile(reader));
+        assert!(first.is_const(reader));
+        assert!(first.is_volatile(reader));
+        assert!(!first.is_by_value(reader));
+        assert_eq!(first.element_type, ElementType::I32);
+
+        let second = SignatureType::from_blob(&mut blob);
+        assert!(second.modifiers.is_empty());
+        assert!(!second.is_const(reader));
+
+        match second.element_type {
+            ElementType::Ptr(modifiers, element_type) => {
+                assert_eq!(modifiers.len(), 1);
+                assert!(!modifiers[0].required);
+                assert_eq!(
+                    modifiers[0].name(reader),
+                    Some(("System.Runtime.CompilerServices", "IsConst"))
+                );
+                assert_eq!(*element_type, ElementType::I32);
+            }
+            _ => panic!("Expected a pointer"),
+        }
+
+        assert!(blob.is_empty());
+    }
+}
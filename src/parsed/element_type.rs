@@ -1,7 +1,8 @@
 use super::*;
 use crate::traits::Decode;
+use crate::TypeReader;
 
-#[derive(Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum ElementType {
     Void,
     Bool,
@@ -19,8 +20,25 @@ pub enum ElementType {
     ISize,
     USize,
     String,
+    Object,
+    TypedByRef,
     Struct(TypeDefOrRef),
     Class(TypeDefOrRef),
+    Var(u32),
+    MVar(u32),
+    Ptr(Vec<CustomModifier>, Box<ElementType>),
+    ByRef(Box<ElementType>),
+    SZArray(Vec<CustomModifier>, Box<ElementType>),
+    Array(Box<ElementType>, ArrayShape),
+    GenericInst(GenericInstance),
+    FnPtr(Box<MethodSignature>),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ArrayShape {
+    pub rank: u32,
+    pub sizes: Vec<u32>,
+    pub lower_bounds: Vec<i32>,
 }
 
 impl ElementType {
@@ -43,12 +61,111 @@ impl ElementType {
             0x18 => ElementType::ISize,
             0x19 => ElementType::USize,
             0x0e => ElementType::String,
+            0x1c => ElementType::Object,
+            0x16 => ElementType::TypedByRef,
             0x11 => {
                 ElementType::Struct(TypeDefOrRef::decode(blob.read_unsigned(), blob.file_index))
             }
             0x12 => ElementType::Class(TypeDefOrRef::decode(blob.read_unsigned(), blob.file_index)),
+            0x13 => ElementType::Var(blob.read_unsigned()),
+            0x1e => ElementType::MVar(blob.read_unsigned()),
+            0x0f => {
+                let modifiers = blob.read_modifiers();
+                ElementType::Ptr(modifiers, Box::new(ElementType::from_blob(blob)))
+            }
+            0x10 => ElementType::ByRef(Box::new(ElementType::from_blob(blob))),
+            0x1d => {
+                let modifiers = blob.read_modifiers();
+                ElementType::SZArray(modifiers, Box::new(ElementType::from_blob(blob)))
+            }
+            0x14 => {
+                let element_type = ElementType::from_blob(blob);
+                let rank = blob.read_unsigned();
+                let sizes = (0..blob.read_unsigned())
+                    .map(|_| blob.read_unsigned())
+                    .collect();
+                let lower_bounds = (0..blob.read_unsigned())
+                    .map(|_| blob.read_signed())
+                    .collect();
+
+                ElementType::Array(
+                    Box::new(element_type),
+                    ArrayShape {
+                        rank,
+                        sizes,
+                        lower_bounds,
+                    },
+                )
+            }
+            0x15 => {
+                let value_type = blob.read_unsigned() == 0x11;
+                let generic_type = TypeDefOrRef::decode(blob.read_unsigned(), blob.file_index);
+                let args = (0..blob.read_unsigned())
+                    .map(|_| ElementType::from_blob(blob))
+                    .collect();
+
+                ElementType::GenericInst(GenericInstance {
+                    generic_type,
+                    value_type,
+                    args,
+                })
+            }
+            0x1b => ElementType::FnPtr(Box::new(MethodSignature::from_blob(blob))),
+
+            unknown_type => panic!("Unexpected ElementType: {:x}", unknown_type),
+        }
+    }
 
-            unknown_type => panic!(format!("Unexpected ElementType: {:x}", unknown_type)),
+    /// Replaces the generic type parameters `Var(n)` with the corresponding type arguments.
+    pub fn substitute(&self, args: &[ElementType]) -> ElementType {
+        match self {
+            ElementType::Var(number) => match args.get(*number as usize) {
+                Some(arg) => arg.clone(),
+                None => self.clone(),
+            },
+            ElementType::Ptr(modifiers, element_type) => {
+                ElementType::Ptr(modifiers.clone(), Box::new(element_type.substitute(args)))
+            }
+            ElementType::ByRef(element_type) => {
+                ElementType::ByRef(Box::new(element_type.substitute(args)))
+            }
+            ElementType::SZArray(modifiers, element_type) => {
+                ElementType::SZArray(modifiers.clone(), Box::new(element_type.substitute(args)))
+            }
+            ElementType::Array(element_type, shape) => {
+                ElementType::Array(Box::new(element_type.substitute(args)), shape.clone())
+            }
+            ElementType::GenericInst(instance) => ElementType::GenericInst(GenericInstance {
+                generic_type: instance.generic_type,
+                value_type: instance.value_type,
+                args: instance
+                    .args
+                    .iter()
+                    .map(|arg| arg.substitute(args))
+                    .collect(),
+            }),
+            ElementType::FnPtr(sig) => ElementType::FnPtr(Box::new(sig.substitute(args))),
+            _ => self.clone(),
+        }
+    }
+
+    /// Compares types by name rather than by row, since types in different files, or even in the
+    /// same file, can refer to the same type through different rows.
+    pub(crate) fn same_type(&self, reader: &TypeReader, other: &ElementType) -> bool {
+        match (self, other) {
+            (ElementType::Class(left), ElementType::Class(right))
+            | (ElementType::Struct(left), ElementType::Struct(right)) => {
+                left.name(reader) == right.name(reader)
+            }
+            (ElementType::SZArray(_, left), ElementType::SZArray(_, right))
+            | (ElementType::Ptr(_, left), ElementType::Ptr(_, right))
+            | (ElementType::ByRef(left), ElementType::ByRef(right)) => {
+                left.same_type(reader, right)
+            }
+            (ElementType::GenericInst(left), ElementType::GenericInst(right)) => {
+                left.same_type(reader, right)
+            }
+            _ => self == other,
         }
     }
 
@@ -70,7 +187,7 @@ impl ElementType {
             0x18 => ElementType::ISize,
             0x19 => ElementType::USize,
             0x0e => ElementType::String,
-            unknown_code => panic!(format!("Unexpected ElementType: {:x}", unknown_code)),
+            unknown_code => panic!("Unexpected ElementType: {:x}", unknown_code),
         }
     }
 }
@@ -0,0 +1,41 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct Event(pub Row);
+
+impl Event {
+    pub fn flags(self, reader: &TypeReader) -> EventFlags {
+        EventFlags(reader.u32(self.0, 0))
+    }
+
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 1)
+    }
+
+    pub fn event_type(self, reader: &TypeReader) -> TypeDefOrRef {
+        reader.decode(self.0, 2)
+    }
+
+    pub fn methods(self, reader: &TypeReader) -> impl Iterator<Item = MethodSemantics> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::MethodSemantics,
+                2,
+                HasSemantics::Event(self).encode(),
+            )
+            .map(MethodSemantics)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::Event(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
@@ -9,14 +9,84 @@ impl Field {
         reader.str(self.0, 1)
     }
 
-    pub fn sig(self, reader: &TypeReader) -> Blob {
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
         reader.blob(self.0, 2)
     }
 
+    pub fn signature(self, reader: &TypeReader) -> SignatureType {
+        let mut sig = self.sig(reader);
+        let field = sig.read_unsigned();
+        debug_assert!(field == 0x06, "A FieldSig must start with 0x06 (Field)");
+        SignatureType::from_blob(&mut sig)
+    }
+
     pub fn flags(self, reader: &TypeReader) -> FieldFlags {
         FieldFlags(reader.u32(self.0, 0))
     }
 
+    pub fn offset(self, reader: &TypeReader) -> Option<u32> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::FieldLayout,
+                1,
+                self.0.index + 1,
+            )
+            .next()
+            .map(|row| reader.u32(row, 0))
+    }
+
+    pub fn marshal(self, reader: &TypeReader) -> Option<FieldMarshal> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::FieldMarshal,
+                0,
+                HasFieldMarshal::Field(self).encode(),
+            )
+            .next()
+            .map(FieldMarshal)
+    }
+
+    pub fn rva(self, reader: &TypeReader) -> Option<u32> {
+        reader
+            .equal_range(self.0.file_index, TableIndex::FieldRva, 1, self.0.index + 1)
+            .next()
+            .map(|row| reader.u32(row, 0))
+    }
+
+    /// The bytes at the field's RVA, sized by the field's type
+    ///
+    /// Only types with a size fixed by the metadata are supported, so this is `None` for
+    /// pointer-sized types such as `ISize`, `USize` and pointers, and for structs without a
+    /// ClassLayout row giving their size.
+    pub fn initial_data(self, reader: &TypeReader) -> Option<&[u8]> {
+        let rva = self.rva(reader)?;
+
+        let size = match self.signature(reader).element_type {
+            ElementType::Bool | ElementType::I8 | ElementType::U8 => 1,
+            ElementType::Char | ElementType::I16 | ElementType::U16 => 2,
+            ElementType::I32 | ElementType::U32 | ElementType::F32 => 4,
+            ElementType::I64 | ElementType::U64 | ElementType::F64 => 8,
+            ElementType::Struct(def) => {
+                match def
+                    .resolve(reader)?
+                    .class_layout(reader)?
+                    .class_size(reader)
+                {
+                    // A class size of zero means the size is left to the runtime.
+                    0 => return None,
+                    size => size,
+                }
+            }
+            _ => return None,
+        };
+
+        let file = &reader.files[self.0.file_index as usize];
+        let offset = file.try_offset_from_rva(rva)? as usize;
+        file.bytes.get(offset..offset.checked_add(size as usize)?)
+    }
+
     pub fn constants(self, reader: &TypeReader) -> impl Iterator<Item = Constant> {
         reader
             .equal_range(
@@ -0,0 +1,19 @@
+use super::*;
+use crate::TypeReader;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct FieldMarshal(pub Row);
+
+impl FieldMarshal {
+    pub fn parent(self, reader: &TypeReader) -> HasFieldMarshal {
+        reader.decode(self.0, 0)
+    }
+
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 1)
+    }
+
+    pub fn native_type(self, reader: &TypeReader) -> Option<NativeType> {
+        NativeType::from_blob(&mut self.sig(reader))
+    }
+}
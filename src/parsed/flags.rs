@@ -9,6 +9,21 @@ pub struct ParamFlags(pub u32);
 #[derive(Default)]
 pub struct FieldFlags(pub u32);
 
+#[derive(Default)]
+pub struct PropertyFlags(pub u32);
+
+#[derive(Default)]
+pub struct EventFlags(pub u32);
+
+#[derive(Default)]
+pub struct GenericParamFlags(pub u32);
+
+#[derive(Default)]
+pub struct PInvokeFlags(pub u32);
+
+#[derive(Default)]
+pub struct AssemblyFlags(pub u32);
+
 impl MethodFlags {
     pub fn special(&self) -> bool {
         self.0 & 0b1000_0000_0000 != 0
@@ -40,6 +55,87 @@ impl FieldFlags {
     }
 }
 
+impl PropertyFlags {
+    pub fn special(&self) -> bool {
+        self.0 & 0b10_0000_0000 != 0
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.0 & 0b1_0000_0000_0000 != 0
+    }
+}
+
+impl EventFlags {
+    pub fn special(&self) -> bool {
+        self.0 & 0b10_0000_0000 != 0
+    }
+}
+
+impl GenericParamFlags {
+    pub fn covariant(&self) -> bool {
+        self.0 & 0b11 == 0b1
+    }
+
+    pub fn contravariant(&self) -> bool {
+        self.0 & 0b11 == 0b10
+    }
+
+    pub fn reference_type(&self) -> bool {
+        self.0 & 0b100 != 0
+    }
+
+    pub fn value_type(&self) -> bool {
+        self.0 & 0b1000 != 0
+    }
+
+    pub fn default_constructor(&self) -> bool {
+        self.0 & 0b1_0000 != 0
+    }
+}
+
+impl PInvokeFlags {
+    pub fn no_mangle(&self) -> bool {
+        self.0 & 0b1 != 0
+    }
+
+    pub fn char_set(&self) -> CharSet {
+        match self.0 & 0b110 {
+            0b010 => CharSet::Ansi,
+            0b100 => CharSet::Unicode,
+            0b110 => CharSet::Auto,
+            _ => CharSet::NotSpecified,
+        }
+    }
+
+    pub fn last_error(&self) -> bool {
+        self.0 & 0b100_0000 != 0
+    }
+
+    pub fn calling_convention(&self) -> CallingConvention {
+        match self.0 & 0b111_0000_0000 {
+            0x200 => CallingConvention::Cdecl,
+            0x300 => CallingConvention::Stdcall,
+            0x400 => CallingConvention::Thiscall,
+            0x500 => CallingConvention::Fastcall,
+            _ => CallingConvention::Winapi,
+        }
+    }
+}
+
+impl AssemblyFlags {
+    pub fn public_key(&self) -> bool {
+        self.0 & 0b1 != 0
+    }
+
+    pub fn retargetable(&self) -> bool {
+        self.0 & 0b1_0000_0000 != 0
+    }
+
+    pub fn windows_runtime(&self) -> bool {
+        self.0 & 0b1110_0000_0000 == 0b10_0000_0000
+    }
+}
+
 #[derive(PartialEq)]
 pub enum TypeCategory {
     Interface,
@@ -69,8 +165,46 @@ pub enum MethodCategory {
     Remove,
 }
 
-#[derive(Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MethodRole {
+    Setter,
+    Getter,
+    Other,
+    AddOn,
+    RemoveOn,
+    Fire,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MethodCallingConvention {
+    Default,
+    C,
+    StdCall,
+    ThisCall,
+    FastCall,
+    VarArg,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CharSet {
+    NotSpecified,
+    Ansi,
+    Unicode,
+    Auto,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum CallingConvention {
+    Winapi,
+    Cdecl,
+    Stdcall,
+    Thiscall,
+    Fastcall,
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub enum AttributeArg {
+    Null,
     Bool(bool),
     Char(char),
     I8(i8),
@@ -84,5 +218,8 @@ pub enum AttributeArg {
     F32(f32),
     F64(f64),
     String(String),
+    Enum(TypeDef, ConstantValue),
     TypeDef(TypeDef),
+    TypeName(TypeName),
+    Array(Vec<AttributeArg>),
 }
@@ -0,0 +1,212 @@
+use super::*;
+use crate::TypeReader;
+
+/// A generic type definition together with its type arguments, such as `IMap<String, Int32>`.
+/// A non-generic type is an instance with no type arguments.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GenericInstance {
+    pub generic_type: TypeDefOrRef,
+    pub value_type: bool,
+    pub args: Vec<ElementType>,
+}
+
+impl GenericInstance {
+    pub fn def(&self, reader: &TypeReader) -> Option<TypeDef> {
+        self.generic_type.resolve(reader)
+    }
+
+    /// The methods of the type with their signatures substituted by the type arguments.
+    pub fn methods<'a>(
+        &'a self,
+        reader: &'a TypeReader,
+    ) -> impl Iterator<Item = (MethodDef, MethodSignature)> + 'a {
+        self.def(reader)
+            .into_iter()
+            .flat_map(move |def| def.methods(reader))
+            .map(move |method| {
+                let sig = method.signature(reader).substitute(&self.args);
+                (method, sig)
+            })
+    }
+
+    /// The properties of the type with their types substituted by the type arguments.
+    pub fn properties<'a>(
+        &'a self,
+        reader: &'a TypeReader,
+    ) -> impl Iterator<Item = (Property, SignatureType)> + 'a {
+        self.def(reader)
+            .into_iter()
+            .flat_map(move |def| def.properties(reader))
+            .map(move |property| {
+                let sig = property.signature(reader).substitute(&self.args);
+                (property, sig)
+            })
+    }
+
+    /// The events of the type with their delegate types substituted by the type arguments.
+    pub fn events<'a>(
+        &'a self,
+        reader: &'a TypeReader,
+    ) -> impl Iterator<Item = (Event, ElementType)> + 'a {
+        self.def(reader)
+            .into_iter()
+            .flat_map(move |def| def.events(reader))
+            .map(move |event| {
+                let event_type = match event.event_type(reader) {
+                    TypeDefOrRef::TypeSpec(spec) => spec.element_type(reader),
+                    event_type => ElementType::Class(event_type),
+                };
+
+                (event, event_type.substitute(&self.args))
+            })
+    }
+
+    /// The interfaces required by the type, including those required transitively by other
+    /// interfaces, instantiated with the type arguments. Each interface appears once.
+    ///
+    /// Interfaces defined in files the reader hasn't loaded are returned by name, but the
+    /// interfaces they require in turn can't be found.
+    pub fn interfaces(&self, reader: &TypeReader) -> Vec<GenericInstance> {
+        let mut interfaces: Vec<GenericInstance> = Vec::new();
+        let mut pending = vec![self.clone()];
+
+        while let Some(instance) = pending.pop() {
+            for interface in instance
+                .def(reader)
+                .into_iter()
+                .flat_map(|def| def.interfaces(reader))
+            {
+                let required = match interface.interface(reader) {
+                    TypeDefOrRef::TypeSpec(spec) => {
+                        match spec.element_type(reader).substitute(&instance.args) {
+                            ElementType::GenericInst(required) => required,
+                            _ => continue,
+                        }
+                    }
+                    generic_type => GenericInstance {
+                        generic_type,
+                        value_type: false,
+                        args: Vec::new(),
+                    },
+                };
+
+                if !interfaces
+                    .iter()
+                    .any(|interface| interface.same_type(reader, &required))
+                {
+                    interfaces.push(required.clone());
+                    pending.push(required);
+                }
+            }
+        }
+
+        interfaces
+    }
+
+    /// Compares the generic types by name and the type arguments with [`ElementType::same_type`].
+    pub(crate) fn same_type(&self, reader: &TypeReader, other: &GenericInstance) -> bool {
+        self.generic_type.name(reader) == other.generic_type.name(reader)
+            && self.args.len() == other.args.len()
+            && self
+                .args
+                .iter()
+                .zip(&other.args)
+                .all(|(left, right)| left.same_type(reader, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+    use std::path::PathBuf;
+
+    #[test]
+    fn same_type_by_name() {
+        let path = PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+        let reader = &TypeReader::from_iter(vec![path]);
+
+        let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IMap`2"));
+        let type_ref = reader
+            .rows(0, crate::TableIndex::TypeRef)
+            .map(TypeRef)
+            .find(|type_ref| type_ref.name(reader) == def.name(reader))
+            .unwrap();
+
+        let instance = |generic_type, args| GenericInstance {
+            generic_type,
+            value_type: false,
+            args,
+        };
+
+        let args = vec![ElementType::String, ElementType::Object];
+        let left = instance(TypeDefOrRef::TypeDef(def), args.clone());
+        let right = instance(TypeDefOrRef::TypeRef(type_ref), args);
+        assert!(left != right);
+        assert!(left.same_type(reader, &right));
+
+        let other = instance(TypeDefOrRef::TypeRef(type_ref), vec![ElementType::String]);
+        assert!(!left.same_type(reader, &other));
+    }
+
+    #[test]
+    fn unresolved_interfaces() {
+        let mut file = TestFile::new();
+        let module = file.string("Test.winmd");
+        let namespace = file.string("Test");
+        let widget = file.string("IWidget");
+        let other = file.string("Other");
+        let closable = file.string("IClosable");
+        let vector = file.string("IVector`1");
+        file.table(TableIndex::Module, &[&[0, module, 0, 0, 0]]);
+        file.table(
+            TableIndex::TypeDef,
+            &[&[0x4020, widget, namespace, 0, 1, 1]],
+        );
+
+        // IWidget requires Other.IClosable and Other.IVector<String> from a file that isn't
+        // loaded.
+        let scope = ResolutionScope::Module(Module(Row::new(0, TableIndex::Module, 0))).encode();
+        file.table(
+            TableIndex::TypeRef,
+            &[&[scope, closable, other], &[scope, vector, other]],
+        );
+        let vector_ref = TypeDefOrRef::TypeRef(TypeRef(Row::new(1, TableIndex::TypeRef, 0)));
+        let sig = file.blob(&[0x15, 0x12, vector_ref.encode() as u8, 0x01, 0x0e]);
+        file.table(TableIndex::TypeSpec, &[&[sig]]);
+        let closable_ref = TypeDefOrRef::TypeRef(TypeRef(Row::new(0, TableIndex::TypeRef, 0)));
+        let vector_spec = TypeDefOrRef::TypeSpec(TypeSpec(Row::new(0, TableIndex::TypeSpec, 0)));
+        file.table(
+            TableIndex::InterfaceImpl,
+            &[&[1, closable_ref.encode()], &[1, vector_spec.encode()]],
+        );
+        let reader = &file.reader();
+
+        let widget = GenericInstance {
+            generic_type: TypeDefOrRef::TypeDef(TypeDef(Row::new(0, TableIndex::TypeDef, 0))),
+            value_type: false,
+            args: Vec::new(),
+        };
+
+        let interfaces = widget.interfaces(reader);
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(
+            interfaces[0].generic_type.name(reader),
+            Some(("Other", "IClosable"))
+        );
+        assert_eq!(
+            interfaces[1].generic_type.name(reader),
+            Some(("Other", "IVector`1"))
+        );
+        assert_eq!(interfaces[1].args, [ElementType::String]);
+
+        for interface in &interfaces {
+            assert_eq!(interface.def(reader), None);
+            assert_eq!(interface.methods(reader).count(), 0);
+            assert_eq!(interface.properties(reader).count(), 0);
+            assert_eq!(interface.events(reader).count(), 0);
+            assert!(interface.interfaces(reader).is_empty());
+        }
+    }
+}
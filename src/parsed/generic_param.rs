@@ -1,11 +1,132 @@
 use super::*;
-use crate::TypeReader;
+use crate::{TableIndex, TypeReader};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
 pub struct GenericParam(pub Row);
 
 impl GenericParam {
+    pub fn number(&self, reader: &TypeReader) -> u32 {
+        reader.u32(self.0, 0)
+    }
+
+    pub fn flags(&self, reader: &TypeReader) -> GenericParamFlags {
+        GenericParamFlags(reader.u32(self.0, 1))
+    }
+
+    pub fn owner(&self, reader: &TypeReader) -> TypeOrMethodDef {
+        reader.decode(self.0, 2)
+    }
+
     pub fn name<'a>(&self, reader: &'a TypeReader) -> &'a str {
         reader.str(self.0, 3)
     }
+
+    pub fn constraints(&self, reader: &TypeReader) -> impl Iterator<Item = GenericParamConstraint> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::GenericParamConstraint,
+                0,
+                self.0.index + 1,
+            )
+            .map(GenericParamConstraint)
+    }
+
+    pub fn attributes(&self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::GenericParam(*self).encode(),
+            )
+            .map(Attribute)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct GenericParamConstraint(pub Row);
+
+impl GenericParamConstraint {
+    pub fn owner(&self, reader: &TypeReader) -> GenericParam {
+        GenericParam(Row::new(
+            reader.u32(self.0, 0) - 1,
+            TableIndex::GenericParam,
+            self.0.file_index,
+        ))
+    }
+
+    pub fn constraint(&self, reader: &TypeReader) -> TypeDefOrRef {
+        reader.decode(self.0, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn variance_and_constraints() {
+        let mut file = TestFile::new();
+        let namespace = file.string("Test");
+        let base = file.string("Base");
+        let producer = file.string("IProducer`1");
+        let consumer = file.string("IConsumer`1");
+        let t = file.string("T");
+        file.table(
+            TableIndex::TypeDef,
+            &[
+                &[0, base, namespace, 0, 1, 1],
+                &[0, producer, namespace, 0, 1, 1],
+                &[0, consumer, namespace, 0, 1, 1],
+            ],
+        );
+
+        let def = |row| TypeDef(Row::new(row, TableIndex::TypeDef, 0));
+        let (base, producer, consumer) = (def(0), def(1), def(2));
+
+        // A covariant `out T` and a contravariant `in T : class, Base`.
+        file.table(
+            TableIndex::GenericParam,
+            &[
+                &[0, 0b1, TypeOrMethodDef::TypeDef(producer).encode(), t],
+                &[0, 0b110, TypeOrMethodDef::TypeDef(consumer).encode(), t],
+            ],
+        );
+        file.table(
+            TableIndex::GenericParamConstraint,
+            &[&[2, TypeDefOrRef::TypeDef(base).encode()]],
+        );
+        let reader = &file.reader();
+
+        let generics: Vec<GenericParam> = producer.generics(reader).collect();
+        assert_eq!(generics.len(), 1);
+        let param = generics[0];
+        assert_eq!(param.number(reader), 0);
+        assert_eq!(param.name(reader), "T");
+        assert_eq!(param.owner(reader), TypeOrMethodDef::TypeDef(producer));
+        assert!(param.flags(reader).covariant());
+        assert!(!param.flags(reader).contravariant());
+        assert!(!param.flags(reader).reference_type());
+        assert_eq!(param.constraints(reader).next(), None);
+
+        let generics: Vec<GenericParam> = consumer.generics(reader).collect();
+        assert_eq!(generics.len(), 1);
+        let param = generics[0];
+        assert_eq!(param.owner(reader), TypeOrMethodDef::TypeDef(consumer));
+        assert!(!param.flags(reader).covariant());
+        assert!(param.flags(reader).contravariant());
+        assert!(param.flags(reader).reference_type());
+        assert!(!param.flags(reader).value_type());
+
+        let constraints: Vec<GenericParamConstraint> = param.constraints(reader).collect();
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(constraints[0].owner(reader), param);
+        assert_eq!(
+            constraints[0].constraint(reader),
+            TypeDefOrRef::TypeDef(base)
+        );
+        assert_eq!(base.generics(reader).next(), None);
+    }
 }
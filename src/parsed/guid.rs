@@ -0,0 +1,153 @@
+use super::*;
+
+use std::convert::TryInto;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Default)]
+pub struct Guid(pub u32, pub u16, pub u16, pub [u8; 8]);
+
+impl Guid {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(
+            u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            u16::from_le_bytes(bytes[4..6].try_into().unwrap()),
+            u16::from_le_bytes(bytes[6..8].try_into().unwrap()),
+            bytes[8..16].try_into().unwrap(),
+        )
+    }
+
+    /// Decode the arguments of a `GuidAttribute`, given either as integer components or as a string
+    pub fn from_args(args: &[(String, AttributeArg)]) -> Option<Self> {
+        match args {
+            [(_, AttributeArg::String(value))] => value.parse().ok(),
+            [(_, AttributeArg::U32(a)), (_, AttributeArg::U16(b)), (_, AttributeArg::U16(c)), rest @ ..]
+                if rest.len() == 8 =>
+            {
+                let mut d = [0; 8];
+                for (byte, (_, arg)) in d.iter_mut().zip(rest) {
+                    match arg {
+                        AttributeArg::U8(value) => *byte = *value,
+                        _ => return None,
+                    }
+                }
+                Some(Self(*a, *b, *c, d))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Guid {
+    type Err = GuidError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || GuidError(value.to_string());
+        // The registry format wraps the GUID in a single pair of braces, which is optional.
+        let trimmed = match (value.strip_prefix('{'), value.strip_suffix('}')) {
+            (Some(_), Some(_)) => &value[1..value.len() - 1],
+            (None, None) => value,
+            _ => return Err(error()),
+        };
+        let parts: Vec<&str> = trimmed.split('-').collect();
+
+        let valid = parts.len() == 5
+            && [8, 4, 4, 4, 12].iter().zip(&parts).all(|(len, part)| {
+                part.len() == *len && part.bytes().all(|byte| byte.is_ascii_hexdigit())
+            });
+
+        if !valid {
+            return Err(error());
+        }
+
+        let parse = |part: &str| u64::from_str_radix(part, 16).map_err(|_| error());
+        let d = (parse(parts[3])? << 48 | parse(parts[4])?).to_be_bytes();
+
+        Ok(Self(
+            parse(parts[0])? as u32,
+            parse(parts[1])? as u16,
+            parse(parts[2])? as u16,
+            d,
+        ))
+    }
+}
+
+/// The error returned when a string is not a GUID in the registry format.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GuidError(pub String);
+
+impl std::fmt::Display for GuidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Invalid GUID string `{}`", self.0)
+    }
+}
+
+impl std::error::Error for GuidError {}
+
+impl std::fmt::Display for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}",
+            self.0,
+            self.1,
+            self.2,
+            self.3[0],
+            self.3[1],
+            self.3[2],
+            self.3[3],
+            self.3[4],
+            self.3[5],
+            self.3[6],
+            self.3[7]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let guid: Guid = "96369f54-8eb6-48f0-abce-c1b211e627c3".parse().unwrap();
+        assert_eq!(
+            guid,
+            Guid(
+                0x9636_9F54,
+                0x8EB6,
+                0x48F0,
+                [0xAB, 0xCE, 0xC1, 0xB2, 0x11, 0xE6, 0x27, 0xC3]
+            )
+        );
+        assert_eq!(guid.to_string(), "96369F54-8EB6-48F0-ABCE-C1B211E627C3");
+        assert_eq!("{96369F54-8EB6-48F0-ABCE-C1B211E627C3}".parse(), Ok(guid));
+    }
+
+    #[test]
+    fn from_bytes() {
+        let bytes = [
+            0x54, 0x9F, 0x36, 0x96, 0xB6, 0x8E, 0xF0, 0x48, 0xAB, 0xCE, 0xC1, 0xB2, 0x11, 0xE6,
+            0x27, 0xC3,
+        ];
+        assert_eq!(
+            Guid::from_bytes(&bytes),
+            "96369F54-8EB6-48F0-ABCE-C1B211E627C3".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn error_on_invalid_string() {
+        for value in &[
+            "96369F54-8EB6-48F0",
+            "96369F54-8EB6-48F0-ABCE-C1B211E627CG",
+            "+6369F54-8EB6-48F0-ABCE-C1B211E627C3",
+            "{96369F54-8EB6-48F0-ABCE-C1B211E627C3",
+            "96369F54-8EB6-48F0-ABCE-C1B211E627C3}",
+            "{{96369F54-8EB6-48F0-ABCE-C1B211E627C3}}",
+            "{{96369F54-8EB6-48F0-ABCE-C1B211E627C3}",
+            "}96369F54-8EB6-48F0-ABCE-C1B211E627C3{",
+            "{}",
+        ] {
+            assert_eq!(value.parse::<Guid>(), Err(GuidError(value.to_string())));
+        }
+    }
+}
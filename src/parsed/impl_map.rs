@@ -0,0 +1,112 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct ImplMap(pub Row);
+
+impl ImplMap {
+    pub fn flags(self, reader: &TypeReader) -> PInvokeFlags {
+        PInvokeFlags(reader.u32(self.0, 0))
+    }
+
+    pub fn member_forwarded(self, reader: &TypeReader) -> MemberForwarded {
+        reader.decode(self.0, 1)
+    }
+
+    pub fn import_name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 2)
+    }
+
+    /// The module the method is imported from, or `None` if the row has a null import scope
+    pub fn import_scope(self, reader: &TypeReader) -> Option<ModuleRef> {
+        let index = reader.u32(self.0, 3).checked_sub(1)?;
+        Some(ModuleRef(Row::new(
+            index,
+            TableIndex::ModuleRef,
+            self.0.file_index,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn imports() {
+        let mut file = TestFile::new();
+        let namespace = file.string("Test");
+        let apis = file.string("Apis");
+        let message_box = file.string("MessageBoxW");
+        let length = file.string("lstrlenA");
+        let helper = file.string("Helper");
+        let orphan = file.string("Orphan");
+        let user32 = file.string("USER32.dll");
+        let kernel32 = file.string("KERNEL32.dll");
+        file.table(TableIndex::TypeDef, &[&[0, apis, namespace, 0, 1, 1]]);
+        file.table(
+            TableIndex::MethodDef,
+            &[
+                &[0, 0, 0, message_box, 0, 1],
+                &[0, 0, 0, length, 0, 1],
+                &[0, 0, 0, helper, 0, 1],
+                &[0, 0, 0, orphan, 0, 1],
+            ],
+        );
+        file.table(TableIndex::ModuleRef, &[&[user32], &[kernel32]]);
+
+        let method = |row| MethodDef(Row::new(row, TableIndex::MethodDef, 0));
+        let forwarded = |row| MemberForwarded::MethodDef(method(row)).encode();
+
+        // NoMangle, Unicode, SetLastError and WinApi, then Ansi and Cdecl, then Auto and
+        // Stdcall with a null import scope.
+        file.table(
+            TableIndex::ImplMap,
+            &[
+                &[0x0145, forwarded(0), message_box, 1],
+                &[0x0202, forwarded(1), length, 2],
+                &[0x0306, forwarded(3), orphan, 0],
+            ],
+        );
+        let reader = &file.reader();
+
+        let import = method(0).impl_map(reader).unwrap();
+        assert_eq!(
+            import.member_forwarded(reader),
+            MemberForwarded::MethodDef(method(0))
+        );
+        assert_eq!(import.import_name(reader), "MessageBoxW");
+        assert_eq!(
+            import.import_scope(reader).unwrap().name(reader),
+            "USER32.dll"
+        );
+        let flags = import.flags(reader);
+        assert!(flags.no_mangle());
+        assert!(flags.last_error());
+        assert_eq!(flags.char_set(), CharSet::Unicode);
+        assert_eq!(flags.calling_convention(), CallingConvention::Winapi);
+
+        let import = method(1).impl_map(reader).unwrap();
+        assert_eq!(import.import_name(reader), "lstrlenA");
+        assert_eq!(
+            import.import_scope(reader).unwrap().name(reader),
+            "KERNEL32.dll"
+        );
+        let flags = import.flags(reader);
+        assert!(!flags.no_mangle());
+        assert!(!flags.last_error());
+        assert_eq!(flags.char_set(), CharSet::Ansi);
+        assert_eq!(flags.calling_convention(), CallingConvention::Cdecl);
+
+        assert!(method(2).impl_map(reader).is_none());
+
+        let import = method(3).impl_map(reader).unwrap();
+        assert_eq!(import.import_scope(reader), None);
+        assert_eq!(import.flags(reader).char_set(), CharSet::Auto);
+        assert_eq!(
+            import.flags(reader).calling_convention(),
+            CallingConvention::Stdcall
+        );
+    }
+}
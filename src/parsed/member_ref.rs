@@ -12,4 +12,43 @@ impl MemberRef {
     pub fn name(self, reader: &TypeReader) -> &str {
         reader.str(self.0, 1)
     }
+
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 2)
+    }
+
+    pub fn signature(self, reader: &TypeReader) -> MethodSignature {
+        MethodSignature::from_blob(&mut self.sig(reader))
+    }
+
+    /// Finds the MethodDef the MemberRef refers to, if its parent type is known to the reader.
+    pub fn resolve(self, reader: &TypeReader) -> Option<MethodDef> {
+        let def = match self.parent(reader) {
+            MemberRefParent::TypeDef(def) => def,
+            MemberRefParent::TypeRef(type_ref) => type_ref.find(reader)?,
+            _ => return None,
+        };
+
+        let name = self.name(reader);
+        let sig = self.signature(reader);
+
+        def.methods(reader).find(|method| {
+            method.name(reader) == name && same_signature(reader, &method.signature(reader), &sig)
+        })
+    }
+}
+
+fn same_signature(reader: &TypeReader, left: &MethodSignature, right: &MethodSignature) -> bool {
+    left.params.len() == right.params.len()
+        && left
+            .return_type
+            .element_type
+            .same_type(reader, &right.return_type.element_type)
+        && left.params.iter().zip(&right.params).all(|(left, right)| {
+            left.sig_type.by_ref == right.sig_type.by_ref
+                && left
+                    .sig_type
+                    .element_type
+                    .same_type(reader, &right.sig_type.element_type)
+        })
 }
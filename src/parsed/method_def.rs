@@ -21,28 +21,72 @@ impl MethodDef {
         reader.str(self.0, 3)
     }
 
-    pub fn sig(self, reader: &TypeReader) -> Blob {
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
         reader.blob(self.0, 4)
     }
 
-    pub fn category(self, reader: &TypeReader) -> MethodCategory {
-        if self.flags(reader).special() {
-            let name = self.name(reader);
-
-            if name.starts_with("get") {
-                MethodCategory::Get
-            } else if name.starts_with("put") {
-                MethodCategory::Set
-            } else if name.starts_with("add") {
-                MethodCategory::Add
-            } else if name.starts_with("remove") {
-                MethodCategory::Remove
-            } else {
-                // A delegate's 'Invoke' method is "special" but lacks a preamble.
-                MethodCategory::Normal
+    pub fn generics(self, reader: &TypeReader) -> impl Iterator<Item = GenericParam> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::GenericParam,
+                2,
+                TypeOrMethodDef::MethodDef(self).encode(),
+            )
+            .map(GenericParam)
+    }
+
+    pub fn specs<'a>(self, reader: &'a TypeReader) -> impl Iterator<Item = MethodSpec> + 'a {
+        // The MethodSpec table is not sorted, so every row is checked.
+        reader
+            .rows(self.0.file_index, TableIndex::MethodSpec)
+            .map(MethodSpec)
+            .filter(move |spec| spec.method(reader) == MethodDefOrRef::MethodDef(self))
+    }
+
+    pub fn impl_map(self, reader: &TypeReader) -> Option<ImplMap> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::ImplMap,
+                1,
+                MemberForwarded::MethodDef(self).encode(),
+            )
+            .next()
+            .map(ImplMap)
+    }
+
+    pub fn semantics(self, reader: &TypeReader) -> Option<MethodSemantics> {
+        reader.method_semantics.get(&self).copied()
+    }
+
+    pub fn signature(self, reader: &TypeReader) -> MethodSignature {
+        let mut signature = MethodSignature::from_blob(&mut self.sig(reader));
+
+        for param in self.params(reader) {
+            match param.sequence(reader) {
+                0 => signature.return_param = Some(param),
+                sequence => {
+                    if let Some(entry) = signature.params.get_mut(sequence as usize - 1) {
+                        entry.param = Some(param);
+                    }
+                }
             }
-        } else {
-            MethodCategory::Normal
+        }
+
+        signature
+    }
+
+    pub fn category(self, reader: &TypeReader) -> MethodCategory {
+        match self
+            .semantics(reader)
+            .map(|semantics| semantics.role(reader))
+        {
+            Some(MethodRole::Getter) => MethodCategory::Get,
+            Some(MethodRole::Setter) => MethodCategory::Set,
+            Some(MethodRole::AddOn) => MethodCategory::Add,
+            Some(MethodRole::RemoveOn) => MethodCategory::Remove,
+            _ => MethodCategory::Normal,
         }
     }
 
@@ -0,0 +1,23 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct MethodImpl(pub Row);
+
+impl MethodImpl {
+    pub fn class(self, reader: &TypeReader) -> TypeDef {
+        TypeDef(Row::new(
+            reader.u32(self.0, 0) - 1,
+            TableIndex::TypeDef,
+            self.0.file_index,
+        ))
+    }
+
+    pub fn body(self, reader: &TypeReader) -> MethodDefOrRef {
+        reader.decode(self.0, 1)
+    }
+
+    pub fn declaration(self, reader: &TypeReader) -> MethodDefOrRef {
+        reader.decode(self.0, 2)
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct MethodSemantics(pub Row);
+
+impl MethodSemantics {
+    pub fn role(self, reader: &TypeReader) -> MethodRole {
+        match reader.u32(self.0, 0) {
+            0x01 => MethodRole::Setter,
+            0x02 => MethodRole::Getter,
+            0x08 => MethodRole::AddOn,
+            0x10 => MethodRole::RemoveOn,
+            0x20 => MethodRole::Fire,
+            _ => MethodRole::Other,
+        }
+    }
+
+    pub fn method(self, reader: &TypeReader) -> MethodDef {
+        MethodDef(Row::new(
+            reader.u32(self.0, 1) - 1,
+            TableIndex::MethodDef,
+            self.0.file_index,
+        ))
+    }
+
+    pub fn association(self, reader: &TypeReader) -> HasSemantics {
+        reader.decode(self.0, 2)
+    }
+}
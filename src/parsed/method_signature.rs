@@ -0,0 +1,170 @@
+use super::*;
+use crate::TypeReader;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MethodSignature {
+    pub calling_convention: MethodCallingConvention,
+    pub has_this: bool,
+    pub explicit_this: bool,
+    pub generic_count: u32,
+    pub return_type: SignatureType,
+    pub return_param: Option<Param>,
+    pub params: Vec<SignatureParam>,
+    /// The index of the first of the variable arguments in a vararg call site signature
+    pub sentinel: Option<usize>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignatureParam {
+    pub param: Option<Param>,
+    pub sig_type: SignatureType,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignatureType {
+    pub modifiers: Vec<CustomModifier>,
+    pub by_ref: bool,
+    pub element_type: ElementType,
+}
+
+impl MethodSignature {
+    pub fn from_blob(blob: &mut Blob) -> Self {
+        let flags = blob.read_u8();
+
+        let calling_convention = match flags & 0x0F {
+            0x01 => MethodCallingConvention::C,
+            0x02 => MethodCallingConvention::StdCall,
+            0x03 => MethodCallingConvention::ThisCall,
+            0x04 => MethodCallingConvention::FastCall,
+            0x05 => MethodCallingConvention::VarArg,
+            _ => MethodCallingConvention::Default,
+        };
+
+        let generic_count = if flags & 0x10 != 0 {
+            blob.read_unsigned()
+        } else {
+            0
+        };
+
+        let param_count = blob.read_unsigned();
+        let return_type = SignatureType::from_blob(blob);
+
+        let mut sentinel = None;
+
+        let params = (0..param_count as usize)
+            .map(|index| {
+                // The sentinel separates the fixed parameters from the variable arguments.
+                if sentinel.is_none() && blob.read_expected(0x41) {
+                    sentinel = Some(index);
+                }
+
+                SignatureParam {
+                    param: None,
+                    sig_type: SignatureType::from_blob(blob),
+                }
+            })
+            .collect();
+
+        Self {
+            calling_convention,
+            has_this: flags & 0x20 != 0,
+            explicit_this: flags & 0x40 != 0,
+            generic_count,
+            return_type,
+            return_param: None,
+            params,
+            sentinel,
+        }
+    }
+}
+
+impl MethodSignature {
+    /// Replaces the generic type parameters in the return type and parameters.
+    pub fn substitute(&self, args: &[ElementType]) -> Self {
+        Self {
+            return_type: self.return_type.substitute(args),
+            params: self
+                .params
+                .iter()
+                .map(|param| SignatureParam {
+                    param: param.param,
+                    sig_type: param.sig_type.substitute(args),
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl SignatureType {
+    pub fn from_blob(blob: &mut Blob) -> Self {
+        let modifiers = blob.read_modifiers();
+        let by_ref = blob.read_expected(0x10);
+        let element_type = ElementType::from_blob(blob);
+
+        Self {
+            modifiers,
+            by_ref,
+            element_type,
+        }
+    }
+
+    pub fn is_const(&self, reader: &TypeReader) -> bool {
+        self.modifiers
+            .iter()
+            .any(|modifier| modifier.is_const(reader))
+    }
+
+    pub fn is_volatile(&self, reader: &TypeReader) -> bool {
+        self.modifiers
+            .iter()
+            .any(|modifier| modifier.is_volatile(reader))
+    }
+
+    pub fn is_by_value(&self, reader: &TypeReader) -> bool {
+        self.modifiers
+            .iter()
+            .any(|modifier| modifier.is_by_value(reader))
+    }
+
+    pub fn substitute(&self, args: &[ElementType]) -> Self {
+        Self {
+            modifiers: self.modifiers.clone(),
+            by_ref: self.by_ref,
+            element_type: self.element_type.substitute(args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+
+    #[test]
+    fn vararg_sentinel() {
+        // A vararg call site signature returning void, with an int32 fixed parameter followed
+        // by string and bool variable arguments.
+        let mut file = TestFile::new();
+        let sig = file.blob(&[0x05, 0x03, 0x01, 0x08, 0x41, 0x0e, 0x02]);
+        file.table(TableIndex::StandaloneSig, &[&[sig]]);
+        let reader = &file.reader();
+
+        let sig = MethodSignature::from_blob(
+            &mut reader.blob(Row::new(0, TableIndex::StandaloneSig, 0), 0),
+        );
+        assert_eq!(sig.calling_convention, MethodCallingConvention::VarArg);
+        assert_eq!(sig.sentinel, Some(1));
+
+        let params: Vec<&ElementType> = sig
+            .params
+            .iter()
+            .map(|param| &param.sig_type.element_type)
+            .collect();
+        assert_eq!(
+            params,
+            [&ElementType::I32, &ElementType::String, &ElementType::Bool]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct MethodSpec(pub Row);
+
+impl MethodSpec {
+    pub fn method(self, reader: &TypeReader) -> MethodDefOrRef {
+        reader.decode(self.0, 0)
+    }
+
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 1)
+    }
+
+    pub fn args(self, reader: &TypeReader) -> Vec<ElementType> {
+        let mut sig = self.sig(reader);
+        let generic_inst = sig.read_unsigned();
+        debug_assert!(
+            generic_inst == 0x0A,
+            "A MethodSpec must start with 0x0A (GenericInst)"
+        );
+
+        let arg_count = sig.read_unsigned();
+        (0..arg_count)
+            .map(|_| ElementType::from_blob(&mut sig))
+            .collect()
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::MethodSpec(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn instantiation() {
+        let mut file = TestFile::new();
+        let namespace = file.string("Test");
+        let class = file.string("Class");
+        let convert = file.string("Convert");
+        let close = file.string("Close");
+        let t_in = file.string("TIn");
+        let t_out = file.string("TOut");
+        file.table(TableIndex::TypeDef, &[&[0, class, namespace, 0, 1, 1]]);
+        file.table(
+            TableIndex::MethodDef,
+            &[&[0, 0, 0, convert, 0, 1], &[0, 0, 0, close, 0, 1]],
+        );
+
+        let method = |row| MethodDef(Row::new(row, TableIndex::MethodDef, 0));
+        let (convert, close) = (method(0), method(1));
+
+        // Convert<TIn, TOut> instantiated as Convert<int32, string>.
+        let owner = TypeOrMethodDef::MethodDef(convert).encode();
+        file.table(
+            TableIndex::GenericParam,
+            &[&[0, 0, owner, t_in], &[1, 0, owner, t_out]],
+        );
+        let instantiation = file.blob(&[0x0a, 0x02, 0x08, 0x0e]);
+        file.table(
+            TableIndex::MethodSpec,
+            &[&[MethodDefOrRef::MethodDef(convert).encode(), instantiation]],
+        );
+        let reader = &file.reader();
+
+        let generics: Vec<GenericParam> = convert.generics(reader).collect();
+        assert_eq!(generics.len(), 2);
+        assert_eq!(generics[0].name(reader), "TIn");
+        assert_eq!(generics[0].number(reader), 0);
+        assert_eq!(generics[1].name(reader), "TOut");
+        assert_eq!(generics[1].number(reader), 1);
+        assert_eq!(
+            generics[0].owner(reader),
+            TypeOrMethodDef::MethodDef(convert)
+        );
+
+        let specs: Vec<MethodSpec> = convert.specs(reader).collect();
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].method(reader), MethodDefOrRef::MethodDef(convert));
+        assert_eq!(
+            specs[0].args(reader),
+            [ElementType::I32, ElementType::String]
+        );
+
+        assert_eq!(close.generics(reader).next(), None);
+        assert_eq!(close.specs(reader).next(), None);
+    }
+}
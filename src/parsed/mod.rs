@@ -1,34 +1,72 @@
 //! Parsed elements of a winmd file
+mod assembly;
+mod assembly_ref;
 mod attribute;
 mod blob;
+mod class_layout;
 mod codes;
 mod constant;
+mod custom_modifier;
 mod element_type;
+mod event;
 mod field;
+mod field_marshal;
 mod flags;
+mod generic_instance;
 mod generic_param;
+mod guid;
+mod impl_map;
 mod interface_impl;
 mod member_ref;
 mod method_def;
+mod method_impl;
+mod method_semantics;
+mod method_signature;
+mod method_spec;
+mod module;
+mod module_ref;
+mod native_type;
 mod param;
+mod property;
 mod row;
 mod type_def;
+mod type_name;
 mod type_ref;
 mod type_spec;
+mod user_string;
 
+pub use assembly::*;
+pub use assembly_ref::*;
 pub use attribute::*;
 pub use blob::*;
+pub use class_layout::*;
 pub use codes::*;
 pub use constant::*;
+pub use custom_modifier::*;
 pub use element_type::*;
+pub use event::*;
 pub use field::*;
+pub use field_marshal::*;
 pub use flags::*;
+pub use generic_instance::*;
 pub use generic_param::*;
+pub use guid::*;
+pub use impl_map::*;
 pub use interface_impl::*;
 pub use member_ref::*;
 pub use method_def::*;
+pub use method_impl::*;
+pub use method_semantics::*;
+pub use method_signature::*;
+pub use method_spec::*;
+pub use module::*;
+pub use module_ref::*;
+pub use native_type::*;
 pub use param::*;
+pub use property::*;
 pub use row::*;
 pub use type_def::*;
+pub use type_name::*;
 pub use type_ref::*;
 pub use type_spec::*;
+pub use user_string::*;
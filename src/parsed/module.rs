@@ -0,0 +1,30 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct Module(pub Row);
+
+impl Module {
+    pub fn generation(self, reader: &TypeReader) -> u32 {
+        reader.u32(self.0, 0)
+    }
+
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 1)
+    }
+
+    pub fn mvid(self, reader: &TypeReader) -> Guid {
+        reader.guid(self.0, 2)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::Module(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
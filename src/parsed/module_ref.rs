@@ -0,0 +1,22 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct ModuleRef(pub Row);
+
+impl ModuleRef {
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 0)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::ModuleRef(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
@@ -0,0 +1,249 @@
+use super::*;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum NativeType {
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    ISize,
+    USize,
+    Currency,
+    BStr,
+    LPStr,
+    LPWStr,
+    LPTStr,
+    LPUtf8Str,
+    ByValTStr(u32),
+    IUnknown,
+    IDispatch,
+    IInspectable,
+    HString,
+    Struct,
+    Interface,
+    SafeArray(Option<u32>),
+    ByValArray {
+        size: u32,
+        element_type: Option<Box<NativeType>>,
+    },
+    ByValStr,
+    AnsiBStr,
+    TBStr,
+    VariantBool,
+    Func,
+    AsAny,
+    Array {
+        element_type: Box<NativeType>,
+        param_index: Option<u32>,
+        size: Option<u32>,
+    },
+    LPStruct,
+    CustomMarshaler {
+        marshaler: String,
+        cookie: String,
+    },
+    Error,
+    Max,
+    /// A NATIVE_TYPE code this crate doesn't model
+    Unknown(u32),
+}
+
+impl NativeType {
+    /// Decodes a marshaling descriptor, returning `None` if the blob ends before the
+    /// descriptor does
+    pub fn from_blob(blob: &mut Blob) -> Option<NativeType> {
+        let native_type = match blob.try_read_unsigned()? {
+            0x02 => NativeType::Bool,
+            0x03 => NativeType::I8,
+            0x04 => NativeType::U8,
+            0x05 => NativeType::I16,
+            0x06 => NativeType::U16,
+            0x07 => NativeType::I32,
+            0x08 => NativeType::U32,
+            0x09 => NativeType::I64,
+            0x0a => NativeType::U64,
+            0x0b => NativeType::F32,
+            0x0c => NativeType::F64,
+            0x0f => NativeType::Currency,
+            0x13 => NativeType::BStr,
+            0x14 => NativeType::LPStr,
+            0x15 => NativeType::LPWStr,
+            0x16 => NativeType::LPTStr,
+            0x17 => NativeType::ByValTStr(blob.try_read_unsigned()?),
+            0x19 => NativeType::IUnknown,
+            0x1a => NativeType::IDispatch,
+            0x1b => NativeType::Struct,
+            0x1c => NativeType::Interface,
+            0x1d => NativeType::SafeArray(if blob.is_empty() {
+                None
+            } else {
+                Some(blob.try_read_unsigned()?)
+            }),
+            0x1e => {
+                let size = blob.try_read_unsigned()?;
+                let element_type = if blob.is_empty() {
+                    None
+                } else {
+                    Some(Box::new(NativeType::from_blob(blob)?))
+                };
+                NativeType::ByValArray { size, element_type }
+            }
+            0x1f => NativeType::ISize,
+            0x20 => NativeType::USize,
+            0x22 => NativeType::ByValStr,
+            0x23 => NativeType::AnsiBStr,
+            0x24 => NativeType::TBStr,
+            0x25 => NativeType::VariantBool,
+            0x26 => NativeType::Func,
+            0x28 => NativeType::AsAny,
+            0x2a => {
+                let element_type = Box::new(NativeType::from_blob(blob)?);
+                let param_index = if blob.is_empty() {
+                    None
+                } else {
+                    Some(blob.try_read_unsigned()?)
+                };
+                let size = if blob.is_empty() {
+                    None
+                } else {
+                    Some(blob.try_read_unsigned()?)
+                };
+                NativeType::Array {
+                    element_type,
+                    param_index,
+                    size,
+                }
+            }
+            0x2b => NativeType::LPStruct,
+            0x2c => {
+                let _guid = blob.try_read_str()?;
+                let _native_type_name = blob.try_read_str()?;
+                let marshaler = blob.try_read_str()?.to_string();
+                let cookie = blob.try_read_str()?.to_string();
+                NativeType::CustomMarshaler { marshaler, cookie }
+            }
+            0x2d => NativeType::Error,
+            0x2e => NativeType::IInspectable,
+            0x2f => NativeType::HString,
+            0x30 => NativeType::LPUtf8Str,
+            0x50 => NativeType::Max,
+            code => NativeType::Unknown(code),
+        };
+
+        Some(native_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+    use crate::TableIndex;
+
+    fn decode(blobs: &[&[u8]]) -> Vec<Option<NativeType>> {
+        let mut file = TestFile::new();
+        let name = file.string("field");
+        let mut fields: Vec<Vec<u32>> = Vec::new();
+        let mut marshals: Vec<Vec<u32>> = Vec::new();
+
+        for (row, blob) in blobs.iter().enumerate() {
+            fields.push(vec![0x1000, name, 0]);
+            let parent = HasFieldMarshal::Field(Field(Row::new(row as u32, TableIndex::Field, 0)));
+            marshals.push(vec![parent.encode(), file.blob(blob)]);
+        }
+
+        let fields: Vec<&[u32]> = fields.iter().map(|row| &row[..]).collect();
+        file.table(TableIndex::Field, &fields);
+        let marshals: Vec<&[u32]> = marshals.iter().map(|row| &row[..]).collect();
+        file.table(TableIndex::FieldMarshal, &marshals);
+        let reader = &file.reader();
+
+        reader
+            .rows(0, TableIndex::FieldMarshal)
+            .map(|row| FieldMarshal(row).native_type(reader))
+            .collect()
+    }
+
+    #[test]
+    fn arrays() {
+        let decoded = decode(&[
+            &[0x2a, 0x07, 0x02, 0x08],
+            &[0x2a, 0x15],
+            &[0x1e, 0x10, 0x04],
+            &[0x1e, 0x04],
+            &[0x1d, 0x08],
+            &[0x1d],
+        ]);
+
+        assert_eq!(
+            decoded,
+            [
+                Some(NativeType::Array {
+                    element_type: Box::new(NativeType::I32),
+                    param_index: Some(2),
+                    size: Some(8),
+                }),
+                Some(NativeType::Array {
+                    element_type: Box::new(NativeType::LPWStr),
+                    param_index: None,
+                    size: None,
+                }),
+                Some(NativeType::ByValArray {
+                    size: 16,
+                    element_type: Some(Box::new(NativeType::U8)),
+                }),
+                Some(NativeType::ByValArray {
+                    size: 4,
+                    element_type: None,
+                }),
+                Some(NativeType::SafeArray(Some(8))),
+                Some(NativeType::SafeArray(None)),
+            ]
+        );
+    }
+
+    #[test]
+    fn strings_and_marshalers() {
+        let mut custom = vec![0x2c, 0x00, 0x00, 0x07];
+        custom.extend(b"Marshal");
+        custom.push(0x03);
+        custom.extend(b"key");
+
+        let decoded = decode(&[&[0x17, 0x20], &custom, &[0x15]]);
+
+        assert_eq!(
+            decoded,
+            [
+                Some(NativeType::ByValTStr(32)),
+                Some(NativeType::CustomMarshaler {
+                    marshaler: "Marshal".to_string(),
+                    cookie: "key".to_string(),
+                }),
+                Some(NativeType::LPWStr),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_and_truncated() {
+        let decoded = decode(&[
+            &[0x7f],
+            &[0x17],
+            &[0x2a],
+            &[0x2c, 0x00, 0x00, 0x07, b'M'],
+            &[],
+        ]);
+
+        assert_eq!(
+            decoded,
+            [Some(NativeType::Unknown(0x7f)), None, None, None, None]
+        );
+    }
+}
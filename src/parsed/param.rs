@@ -1,5 +1,5 @@
 use super::*;
-use crate::TypeReader;
+use crate::{TableIndex, TypeReader};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
 pub struct Param(pub Row);
@@ -16,4 +16,16 @@ impl Param {
     pub fn name(self, reader: &TypeReader) -> &str {
         reader.str(self.0, 2)
     }
+
+    pub fn marshal(self, reader: &TypeReader) -> Option<FieldMarshal> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::FieldMarshal,
+                0,
+                HasFieldMarshal::Param(self).encode(),
+            )
+            .next()
+            .map(FieldMarshal)
+    }
 }
@@ -0,0 +1,52 @@
+use super::*;
+use crate::{TableIndex, TypeReader};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+pub struct Property(pub Row);
+
+impl Property {
+    pub fn flags(self, reader: &TypeReader) -> PropertyFlags {
+        PropertyFlags(reader.u32(self.0, 0))
+    }
+
+    pub fn name(self, reader: &TypeReader) -> &str {
+        reader.str(self.0, 1)
+    }
+
+    pub fn sig(self, reader: &TypeReader) -> Blob<'_> {
+        reader.blob(self.0, 2)
+    }
+
+    pub fn signature(self, reader: &TypeReader) -> SignatureType {
+        let mut sig = self.sig(reader);
+        let property = sig.read_u8();
+        debug_assert!(
+            property & 0x08 != 0,
+            "A PropertySig must start with 0x08 (Property)"
+        );
+        sig.read_unsigned();
+        SignatureType::from_blob(&mut sig)
+    }
+
+    pub fn methods(self, reader: &TypeReader) -> impl Iterator<Item = MethodSemantics> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::MethodSemantics,
+                2,
+                HasSemantics::Property(self).encode(),
+            )
+            .map(MethodSemantics)
+    }
+
+    pub fn attributes(self, reader: &TypeReader) -> impl Iterator<Item = Attribute> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::CustomAttribute,
+                0,
+                HasAttribute::Property(self).encode(),
+            )
+            .map(Attribute)
+    }
+}
@@ -25,6 +25,76 @@ impl TypeDef {
         reader.list(self.0, TableIndex::MethodDef, 5).map(MethodDef)
     }
 
+    pub fn method_impls(self, reader: &TypeReader) -> impl Iterator<Item = MethodImpl> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::MethodImpl,
+                0,
+                self.0.index + 1,
+            )
+            .map(MethodImpl)
+    }
+
+    pub fn properties(self, reader: &TypeReader) -> impl Iterator<Item = Property> {
+        reader
+            .property_maps
+            .get(&self)
+            .map(|map| reader.list(*map, TableIndex::Property, 1))
+            .into_iter()
+            .flatten()
+            .map(Property)
+    }
+
+    pub fn events(self, reader: &TypeReader) -> impl Iterator<Item = Event> {
+        reader
+            .event_maps
+            .get(&self)
+            .map(|map| reader.list(*map, TableIndex::Event, 1))
+            .into_iter()
+            .flatten()
+            .map(Event)
+    }
+
+    pub fn class_layout(self, reader: &TypeReader) -> Option<ClassLayout> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::ClassLayout,
+                2,
+                self.0.index + 1,
+            )
+            .next()
+            .map(ClassLayout)
+    }
+
+    pub fn enclosing_type(self, reader: &TypeReader) -> Option<TypeDef> {
+        reader
+            .equal_range(
+                self.0.file_index,
+                TableIndex::NestedClass,
+                0,
+                self.0.index + 1,
+            )
+            .next()
+            .map(|row| {
+                TypeDef(Row::new(
+                    reader.u32(row, 1) - 1,
+                    TableIndex::TypeDef,
+                    self.0.file_index,
+                ))
+            })
+    }
+
+    pub fn nested_types<'a>(self, reader: &'a TypeReader) -> impl Iterator<Item = TypeDef> + 'a {
+        reader
+            .nested
+            .get(&self)
+            .into_iter()
+            .chain(reader.excluded_nested.get(&self))
+            .flat_map(|types| types.values().copied())
+    }
+
     pub fn generics(self, reader: &TypeReader) -> impl Iterator<Item = GenericParam> {
         reader
             .equal_range(
@@ -69,6 +139,13 @@ impl TypeDef {
             .unwrap()
     }
 
+    pub fn guid(self, reader: &TypeReader) -> Option<Guid> {
+        self.attributes(reader)
+            .find(|attribute| GUID_ATTRIBUTES.contains(&attribute.name(reader)))
+            .and_then(|attribute| attribute.args(reader).ok())
+            .and_then(|args| Guid::from_args(&args))
+    }
+
     pub fn is_winrt(self, reader: &TypeReader) -> bool {
         let flags = self.flags(reader);
 
@@ -78,11 +155,11 @@ impl TypeDef {
             true
         } else {
             match self.extends(reader).name(reader) {
-                ("System", "ValueType") => !self.has_attribute(
+                Some(("System", "ValueType")) => !self.has_attribute(
                     reader,
                     ("Windows.Foundation.Metadata", "ApiContractAttribute"),
                 ),
-                ("System", "Attribute") => false,
+                Some(("System", "Attribute")) => false,
                 _ => true,
             }
         }
@@ -93,9 +170,9 @@ impl TypeDef {
             TypeCategory::Interface
         } else {
             match self.extends(reader).name(reader) {
-                ("System", "Enum") => TypeCategory::Enum,
-                ("System", "MulticastDelegate") => TypeCategory::Delegate,
-                ("System", "ValueType") => TypeCategory::Struct,
+                Some(("System", "Enum")) => TypeCategory::Enum,
+                Some(("System", "MulticastDelegate")) => TypeCategory::Delegate,
+                Some(("System", "ValueType")) => TypeCategory::Struct,
                 _ => TypeCategory::Class,
             }
         }
@@ -103,7 +180,7 @@ impl TypeDef {
 
     pub fn underlying_type(self, reader: &TypeReader) -> ElementType {
         for field in self.fields(reader) {
-            for constant in field.constants(reader) {
+            if let Some(constant) = field.constants(reader).next() {
                 return constant.value_type(reader);
             }
         }
@@ -111,3 +188,63 @@ impl TypeDef {
         panic!("TypeDef::underlying_type");
     }
 }
+
+/// The attributes that give a type's GUID: the WinRT attribute and the integer and string
+/// forms used by Win32 metadata.
+const GUID_ATTRIBUTES: [(&str, &str); 3] = [
+    ("Windows.Foundation.Metadata", "GuidAttribute"),
+    ("Windows.Win32.Interop", "GuidAttribute"),
+    ("System.Runtime.InteropServices", "GuidAttribute"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::TestFile;
+
+    #[test]
+    fn nested_types() {
+        let mut file = TestFile::new();
+        let module = file.string("Test.winmd");
+        let namespace = file.string("Test");
+        let outer = file.string("Outer");
+        let inner = file.string("Inner");
+        file.table(TableIndex::Module, &[&[0, module, 0, 0, 0]]);
+        file.table(
+            TableIndex::TypeDef,
+            &[&[0, outer, namespace, 0, 1, 1], &[0, inner, 0, 0, 1, 1]],
+        );
+        file.table(TableIndex::NestedClass, &[&[2, 1]]);
+        // References to Outer within the module and to Inner through Outer.
+        let module_scope = ResolutionScope::Module(Module(Row::new(0, TableIndex::Module, 0)));
+        let outer_scope = ResolutionScope::TypeRef(TypeRef(Row::new(0, TableIndex::TypeRef, 0)));
+        file.table(
+            TableIndex::TypeRef,
+            &[
+                &[module_scope.encode(), outer, namespace],
+                &[outer_scope.encode(), inner, 0],
+            ],
+        );
+        let reader = &file.reader();
+
+        let outer = TypeDef(Row::new(0, TableIndex::TypeDef, 0));
+        let inner = TypeDef(Row::new(1, TableIndex::TypeDef, 0));
+        assert_eq!(outer.enclosing_type(reader), None);
+        assert_eq!(inner.enclosing_type(reader), Some(outer));
+        assert_eq!(outer.nested_types(reader).collect::<Vec<_>>(), [inner]);
+        assert_eq!(inner.nested_types(reader).next(), None);
+        assert_eq!(reader.resolve_nested_type_def(outer, "Inner"), inner);
+        assert_eq!(reader.find_nested_type_def(outer, "Outer"), None);
+
+        let types: Vec<(&str, &TypeDef)> = reader.namespace_types("Test").collect();
+        assert_eq!(types, [("Outer", &outer)]);
+        assert_eq!(reader.find_type_def(("", "Inner")), None);
+
+        let outer_ref = TypeRef(Row::new(0, TableIndex::TypeRef, 0));
+        let inner_ref = TypeRef(Row::new(1, TableIndex::TypeRef, 0));
+        assert_eq!(outer_ref.enclosing_type(reader), None);
+        assert_eq!(inner_ref.enclosing_type(reader), Some(outer_ref));
+        assert_eq!(outer_ref.resolve(reader), outer);
+        assert_eq!(inner_ref.resolve(reader), inner);
+    }
+}
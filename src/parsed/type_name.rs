@@ -0,0 +1,429 @@
+use super::*;
+use crate::TypeReader;
+
+/// A type name as serialized in custom attributes for arguments of type `System.Type`, such as
+/// ``Windows.Foundation.IReference`1[[System.Int32, mscorlib]], Windows.Foundation``.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TypeName {
+    pub namespace: String,
+    pub name: String,
+    pub nested: Vec<String>,
+    pub generic_args: Vec<TypeName>,
+    /// The array, pointer and byref suffixes in the order they appear, innermost first.
+    pub suffixes: Vec<TypeNameSuffix>,
+    pub assembly: Option<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TypeNameSuffix {
+    /// `*`
+    Pointer,
+    /// `&`
+    ByRef,
+    /// `[]`
+    SZArray,
+    /// `[*]` for a single dimension, or `[,]` and so on for the given rank.
+    Array(u32),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum TypeNameError {
+    /// The type name is malformed at the given byte offset.
+    Syntax(String, usize),
+    /// No type with the given name is defined in the reader's files.
+    NotFound(String),
+}
+
+impl TypeName {
+    pub fn parse(value: &str) -> Result<Self, TypeNameError> {
+        let mut parser = Parser { value, offset: 0 };
+        let type_name = parser.type_name(true)?;
+
+        if parser.value[parser.offset..].trim().is_empty() {
+            Ok(type_name)
+        } else {
+            Err(parser.error())
+        }
+    }
+
+    /// Resolves the type, or the generic type definition for a generic instantiation and the
+    /// element type for arrays, pointers and byrefs. The assembly name is ignored since types
+    /// are found by name in all of the reader's files.
+    pub fn resolve(&self, reader: &TypeReader) -> Result<TypeDef, TypeNameError> {
+        let not_found = || TypeNameError::NotFound(self.to_string());
+        let mut def = reader
+            .find_any_type_def((&self.namespace, &self.name))
+            .ok_or_else(not_found)?;
+
+        for nested in &self.nested {
+            def = reader
+                .find_any_nested_type_def(def, nested)
+                .ok_or_else(not_found)?;
+        }
+
+        Ok(def)
+    }
+}
+
+impl std::fmt::Display for TypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.namespace.is_empty() {
+            write!(f, "{}.", Escaped(&self.namespace, false))?;
+        }
+
+        write!(f, "{}", Escaped(&self.name, true))?;
+
+        for nested in &self.nested {
+            write!(f, "+{}", Escaped(nested, false))?;
+        }
+
+        if !self.generic_args.is_empty() {
+            write!(f, "[")?;
+
+            for (index, arg) in self.generic_args.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ",")?;
+                }
+
+                write!(f, "[{}]", arg)?;
+            }
+
+            write!(f, "]")?;
+        }
+
+        for suffix in &self.suffixes {
+            match suffix {
+                TypeNameSuffix::Pointer => write!(f, "*")?,
+                TypeNameSuffix::ByRef => write!(f, "&")?,
+                TypeNameSuffix::SZArray => write!(f, "[]")?,
+                TypeNameSuffix::Array(1) => write!(f, "[*]")?,
+                TypeNameSuffix::Array(rank) => write!(f, "[{}]", ",".repeat(*rank as usize - 1))?,
+            }
+        }
+
+        if let Some(assembly) = &self.assembly {
+            write!(f, ", {}", assembly)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes an identifier with its special characters escaped, including `.` when the
+/// identifier follows the namespace.
+struct Escaped<'a>(&'a str, bool);
+
+impl<'a> std::fmt::Display for Escaped<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in self.0.chars() {
+            if is_special(c) || (self.1 && c == '.') {
+                write!(f, "\\")?;
+            }
+
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_special(c: char) -> bool {
+    matches!(c, '+' | '[' | ']' | ',' | '&' | '*' | '\\')
+}
+
+impl std::fmt::Display for TypeNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TypeNameError::Syntax(value, offset) => {
+                write!(f, "Invalid type name `{}` at offset {}", value, offset)
+            }
+            TypeNameError::NotFound(value) => write!(f, "Could not find type `{}`", value),
+        }
+    }
+}
+
+impl std::error::Error for TypeNameError {}
+
+struct Parser<'a> {
+    value: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> TypeNameError {
+        TypeNameError::Syntax(self.value.to_string(), self.offset)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.value[self.offset..].chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let rest = &self.value[self.offset..];
+        let trimmed = rest.trim_start();
+
+        if trimmed.starts_with(expected) {
+            self.offset += rest.len() - trimmed.len() + expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the first two characters ahead, skipping whitespace.
+    fn lookahead(&self) -> (Option<char>, Option<char>) {
+        let mut chars = self.value[self.offset..]
+            .chars()
+            .filter(|c| !c.is_whitespace());
+        (chars.next(), chars.next())
+    }
+
+    /// Reads an identifier, resolving escapes, along with the offset of its last unescaped `.`.
+    fn identifier(&mut self) -> Result<(String, Option<usize>), TypeNameError> {
+        let mut identifier = String::new();
+        let mut dot = None;
+
+        while let Some(c) = self.peek() {
+            match c {
+                '\\' => {
+                    self.offset += 1;
+                    let escaped = self.peek().ok_or_else(|| self.error())?;
+                    identifier.push(escaped);
+                    self.offset += escaped.len_utf8();
+                }
+                c if is_special(c) => break,
+                _ => {
+                    if c == '.' {
+                        dot = Some(identifier.len());
+                    }
+
+                    identifier.push(c);
+                    self.offset += c.len_utf8();
+                }
+            }
+        }
+
+        let leading = identifier.len() - identifier.trim_start().len();
+        let identifier = identifier.trim();
+
+        if identifier.is_empty() {
+            Err(self.error())
+        } else {
+            let dot = dot.filter(|dot| *dot >= leading).map(|dot| dot - leading);
+            Ok((identifier.to_string(), dot))
+        }
+    }
+
+    fn type_name(&mut self, qualified: bool) -> Result<TypeName, TypeNameError> {
+        let (full_name, dot) = self.identifier()?;
+
+        let (namespace, name) = match dot {
+            Some(index) => (
+                full_name[..index].to_string(),
+                full_name[index + 1..].to_string(),
+            ),
+            None => (String::new(), full_name),
+        };
+
+        let mut nested = Vec::new();
+
+        while self.eat('+') {
+            nested.push(self.identifier()?.0);
+        }
+
+        let mut generic_args = Vec::new();
+
+        // A bracket starts the generic arguments unless it starts an array suffix instead.
+        if let (Some('['), Some(next)) = self.lookahead() {
+            if !matches!(next, ']' | ',' | '*') {
+                self.eat('[');
+
+                loop {
+                    // Generic arguments are assembly-qualified only when enclosed in their own brackets.
+                    if self.eat('[') {
+                        generic_args.push(self.type_name(true)?);
+
+                        if !self.eat(']') {
+                            return Err(self.error());
+                        }
+                    } else {
+                        generic_args.push(self.type_name(false)?);
+                    }
+
+                    if self.eat(']') {
+                        break;
+                    }
+
+                    if !self.eat(',') {
+                        return Err(self.error());
+                    }
+                }
+            }
+        }
+
+        let mut suffixes = Vec::new();
+
+        loop {
+            if self.eat('*') {
+                suffixes.push(TypeNameSuffix::Pointer);
+            } else if self.eat('&') {
+                suffixes.push(TypeNameSuffix::ByRef);
+            } else if self.eat('[') {
+                if self.eat('*') {
+                    suffixes.push(TypeNameSuffix::Array(1));
+                } else {
+                    let mut rank = 1;
+
+                    while self.eat(',') {
+                        rank += 1;
+                    }
+
+                    suffixes.push(if rank == 1 {
+                        TypeNameSuffix::SZArray
+                    } else {
+                        TypeNameSuffix::Array(rank)
+                    });
+                }
+
+                if !self.eat(']') {
+                    return Err(self.error());
+                }
+            } else {
+                break;
+            }
+        }
+
+        let assembly = if qualified && self.eat(',') {
+            Some(self.assembly_name()?)
+        } else {
+            None
+        };
+
+        Ok(TypeName {
+            namespace,
+            name,
+            nested,
+            generic_args,
+            suffixes,
+            assembly,
+        })
+    }
+
+    fn assembly_name(&mut self) -> Result<String, TypeNameError> {
+        // The assembly name contains commas of its own and runs to the closing bracket, if any.
+        let rest = &self.value[self.offset..];
+        let len = rest.find(']').unwrap_or(rest.len());
+        let assembly = rest[..len].trim();
+
+        if assembly.is_empty() {
+            return Err(self.error());
+        }
+
+        self.offset += len;
+        Ok(assembly.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let name = TypeName::parse("Windows.Foundation.IStringable").unwrap();
+        assert!(name.namespace == "Windows.Foundation");
+        assert!(name.name == "IStringable");
+        assert!(name.nested.is_empty());
+        assert!(name.generic_args.is_empty());
+        assert!(name.assembly.is_none());
+    }
+
+    #[test]
+    fn nested() {
+        let name = TypeName::parse("Outer.Namespace.Outer+Middle+Inner").unwrap();
+        assert!(name.namespace == "Outer.Namespace");
+        assert!(name.name == "Outer");
+        assert!(name.nested == vec!["Middle", "Inner"]);
+    }
+
+    #[test]
+    fn generic() {
+        let value = "Windows.Foundation.Collections.IMap`2[[System.String, mscorlib, Version=4.0.0.0],[Windows.Foundation.IReference`1[System.Int32]]], Windows, Version=255.255.255.255";
+        let name = TypeName::parse(value).unwrap();
+        assert!(name.name == "IMap`2");
+        assert!(name.assembly.as_deref() == Some("Windows, Version=255.255.255.255"));
+        assert!(name.generic_args.len() == 2);
+
+        let key = &name.generic_args[0];
+        assert!(key.namespace == "System");
+        assert!(key.name == "String");
+        assert!(key.assembly.as_deref() == Some("mscorlib, Version=4.0.0.0"));
+
+        let value = &name.generic_args[1];
+        assert!(value.name == "IReference`1");
+        assert!(value.assembly.is_none());
+        assert!(value.generic_args[0].name == "Int32");
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = "A.B`1+C[[D.E, F]], G";
+        assert!(TypeName::parse(value).unwrap().to_string() == value);
+    }
+
+    #[test]
+    fn escaped() {
+        let name = TypeName::parse("A.B\\+C").unwrap();
+        assert!(name.name == "B+C");
+        assert!(name.nested.is_empty());
+
+        let name = TypeName::parse("A.B\\.C").unwrap();
+        assert!(name.namespace == "A");
+        assert!(name.name == "B.C");
+
+        let name = TypeName::parse("B\\.C").unwrap();
+        assert!(name.namespace.is_empty());
+        assert!(name.name == "B.C");
+        assert!(name.to_string() == "B\\.C");
+    }
+
+    #[test]
+    fn suffixes() {
+        let suffixes = |value| TypeName::parse(value).unwrap().suffixes;
+        assert!(suffixes("A.B[]") == vec![TypeNameSuffix::SZArray]);
+        assert!(suffixes("A.B[,]") == vec![TypeNameSuffix::Array(2)]);
+        assert!(suffixes("A.B[*]") == vec![TypeNameSuffix::Array(1)]);
+        assert!(suffixes("A.B*") == vec![TypeNameSuffix::Pointer]);
+        assert!(suffixes("A.B&") == vec![TypeNameSuffix::ByRef]);
+
+        let name = TypeName::parse("A.B`1[[C.D, E]][][,,]*&, F").unwrap();
+        assert!(name.generic_args.len() == 1);
+        assert!(name.generic_args[0].assembly.as_deref() == Some("E"));
+        assert!(
+            name.suffixes
+                == vec![
+                    TypeNameSuffix::SZArray,
+                    TypeNameSuffix::Array(3),
+                    TypeNameSuffix::Pointer,
+                    TypeNameSuffix::ByRef
+                ]
+        );
+        assert!(name.assembly.as_deref() == Some("F"));
+        assert!(name.to_string() == "A.B`1[[C.D, E]][][,,]*&, F");
+
+        let name = TypeName::parse("A.B`1[C.D[]]").unwrap();
+        assert!(name.suffixes.is_empty());
+        assert!(name.generic_args[0].suffixes == vec![TypeNameSuffix::SZArray]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(TypeName::parse("") == Err(TypeNameError::Syntax(String::new(), 0)));
+        assert!(TypeName::parse("A.B[[C.D]").is_err());
+        assert!(TypeName::parse("A.B+").is_err());
+        assert!(TypeName::parse("A.B[,").is_err());
+        assert!(TypeName::parse("A.B[*,]").is_err());
+        assert!(TypeName::parse("A.B, ").is_err());
+        assert!(TypeName::parse("A.B]").is_err());
+    }
+}
@@ -9,7 +9,34 @@ impl TypeRef {
         (reader.str(self.0, 2), reader.str(self.0, 1))
     }
 
+    pub fn scope(self, reader: &TypeReader) -> ResolutionScope {
+        reader.decode(self.0, 0)
+    }
+
+    pub fn enclosing_type(self, reader: &TypeReader) -> Option<TypeRef> {
+        // A TypeRef resolution scope indicates a nested type.
+        match self.scope(reader) {
+            ResolutionScope::TypeRef(enclosing) => Some(enclosing),
+            _ => None,
+        }
+    }
+
     pub fn resolve(self, reader: &TypeReader) -> TypeDef {
-        reader.resolve_type_def(self.name(reader))
+        if let Some(enclosing) = self.enclosing_type(reader) {
+            reader.resolve_nested_type_def(enclosing.resolve(reader), self.name(reader).1)
+        } else {
+            reader.resolve_type_def(self.name(reader))
+        }
+    }
+
+    /// Finds the TypeDef the TypeRef refers to, if it is defined in one of the reader's files.
+    /// Unlike [`TypeRef::resolve`] this also finds types left out of the reader's namespaces,
+    /// such as the attribute types in WinRT metadata.
+    pub fn find(self, reader: &TypeReader) -> Option<TypeDef> {
+        if let Some(enclosing) = self.enclosing_type(reader) {
+            reader.find_any_nested_type_def(enclosing.find(reader)?, self.name(reader).1)
+        } else {
+            reader.find_any_type_def(self.name(reader))
+        }
     }
 }
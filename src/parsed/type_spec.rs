@@ -8,4 +8,27 @@ impl TypeSpec {
     pub fn sig<'a>(&self, reader: &'a TypeReader) -> Blob<'a> {
         reader.blob(self.0, 0)
     }
+
+    pub fn element_type(&self, reader: &TypeReader) -> ElementType {
+        ElementType::from_blob(&mut self.sig(reader))
+    }
+
+    /// Decodes the TypeSpec as a generic instantiation, which is the only kind of TypeSpec
+    /// found in WinRT metadata. Returns `None` for arrays, pointers and generic parameters.
+    pub fn decode(&self, reader: &TypeReader) -> Option<GenericInstance> {
+        match self.element_type(reader) {
+            ElementType::GenericInst(instance) => Some(instance),
+            _ => None,
+        }
+    }
+
+    /// The TypeDef or TypeRef named by the TypeSpec, which for a generic instantiation is its
+    /// generic type definition.
+    pub fn definition(&self, reader: &TypeReader) -> Option<TypeDefOrRef> {
+        match self.element_type(reader) {
+            ElementType::GenericInst(instance) => Some(instance.generic_type),
+            ElementType::Class(value) | ElementType::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,10 @@
+/// A string literal stored in the `#US` heap
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct UserString {
+    /// The offset of the string within the `#US` heap
+    pub index: u32,
+    /// The decoded UTF-16 value of the string
+    pub value: String,
+    /// Whether the trailing byte marks the string as containing characters that need special handling
+    pub special: bool,
+}
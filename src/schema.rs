@@ -0,0 +1,433 @@
+use crate::TableIndex;
+
+/// The kind of value stored in a table column
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ColumnKind {
+    /// A fixed-size integer value
+    Integer,
+    /// An index into the `#Strings` heap
+    String,
+    /// An index into the `#GUID` heap
+    Guid,
+    /// An index into the `#Blob` heap
+    Blob,
+    /// A one-based index into another table
+    Table(TableIndex),
+    /// A coded index into one of several tables
+    Coded(CodedIndex),
+}
+
+/// The coded index kinds defined by ECMA-335 II.24.2.6
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CodedIndex {
+    TypeDefOrRef,
+    HasConstant,
+    HasCustomAttribute,
+    HasFieldMarshal,
+    HasDeclSecurity,
+    MemberRefParent,
+    HasSemantics,
+    MethodDefOrRef,
+    MemberForwarded,
+    Implementation,
+    CustomAttributeType,
+    ResolutionScope,
+    TypeOrMethodDef,
+}
+
+/// The name and kind of a table column
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Column {
+    pub name: &'static str,
+    pub kind: ColumnKind,
+}
+
+use CodedIndex::*;
+use ColumnKind::*;
+
+macro_rules! columns {
+    ($(($name:literal, $kind:expr)),* $(,)?) => {
+        &[$(Column { name: $name, kind: $kind }),*]
+    };
+}
+
+impl TableIndex {
+    /// The ECMA-335 id of the table
+    pub fn id(self) -> u8 {
+        match self {
+            TableIndex::Module => 0x00,
+            TableIndex::TypeRef => 0x01,
+            TableIndex::TypeDef => 0x02,
+            TableIndex::Field => 0x04,
+            TableIndex::MethodDef => 0x06,
+            TableIndex::Param => 0x08,
+            TableIndex::InterfaceImpl => 0x09,
+            TableIndex::MemberRef => 0x0a,
+            TableIndex::Constant => 0x0b,
+            TableIndex::CustomAttribute => 0x0c,
+            TableIndex::FieldMarshal => 0x0d,
+            TableIndex::DeclSecurity => 0x0e,
+            TableIndex::ClassLayout => 0x0f,
+            TableIndex::FieldLayout => 0x10,
+            TableIndex::StandaloneSig => 0x11,
+            TableIndex::EventMap => 0x12,
+            TableIndex::Event => 0x14,
+            TableIndex::PropertyMap => 0x15,
+            TableIndex::Property => 0x17,
+            TableIndex::MethodSemantics => 0x18,
+            TableIndex::MethodImpl => 0x19,
+            TableIndex::ModuleRef => 0x1a,
+            TableIndex::TypeSpec => 0x1b,
+            TableIndex::ImplMap => 0x1c,
+            TableIndex::FieldRva => 0x1d,
+            TableIndex::Assembly => 0x20,
+            TableIndex::AssemblyProcessor => 0x21,
+            TableIndex::AssemblyOs => 0x22,
+            TableIndex::AssemblyRef => 0x23,
+            TableIndex::AssemblyRefProcessor => 0x24,
+            TableIndex::AssemblyRefOs => 0x25,
+            TableIndex::File => 0x26,
+            TableIndex::ExportedType => 0x27,
+            TableIndex::ManifestResource => 0x28,
+            TableIndex::NestedClass => 0x29,
+            TableIndex::GenericParam => 0x2a,
+            TableIndex::MethodSpec => 0x2b,
+            TableIndex::GenericParamConstraint => 0x2c,
+        }
+    }
+
+    /// The table with the given ECMA-335 id, if it is supported
+    pub fn from_id(id: u8) -> Option<Self> {
+        let table = match id {
+            0x00 => TableIndex::Module,
+            0x01 => TableIndex::TypeRef,
+            0x02 => TableIndex::TypeDef,
+            0x04 => TableIndex::Field,
+            0x06 => TableIndex::MethodDef,
+            0x08 => TableIndex::Param,
+            0x09 => TableIndex::InterfaceImpl,
+            0x0a => TableIndex::MemberRef,
+            0x0b => TableIndex::Constant,
+            0x0c => TableIndex::CustomAttribute,
+            0x0d => TableIndex::FieldMarshal,
+            0x0e => TableIndex::DeclSecurity,
+            0x0f => TableIndex::ClassLayout,
+            0x10 => TableIndex::FieldLayout,
+            0x11 => TableIndex::StandaloneSig,
+            0x12 => TableIndex::EventMap,
+            0x14 => TableIndex::Event,
+            0x15 => TableIndex::PropertyMap,
+            0x17 => TableIndex::Property,
+            0x18 => TableIndex::MethodSemantics,
+            0x19 => TableIndex::MethodImpl,
+            0x1a => TableIndex::ModuleRef,
+            0x1b => TableIndex::TypeSpec,
+            0x1c => TableIndex::ImplMap,
+            0x1d => TableIndex::FieldRva,
+            0x20 => TableIndex::Assembly,
+            0x21 => TableIndex::AssemblyProcessor,
+            0x22 => TableIndex::AssemblyOs,
+            0x23 => TableIndex::AssemblyRef,
+            0x24 => TableIndex::AssemblyRefProcessor,
+            0x25 => TableIndex::AssemblyRefOs,
+            0x26 => TableIndex::File,
+            0x27 => TableIndex::ExportedType,
+            0x28 => TableIndex::ManifestResource,
+            0x29 => TableIndex::NestedClass,
+            0x2a => TableIndex::GenericParam,
+            0x2b => TableIndex::MethodSpec,
+            0x2c => TableIndex::GenericParamConstraint,
+            _ => return None,
+        };
+        Some(table)
+    }
+
+    /// The columns of the table, as defined by ECMA-335 II.22
+    pub fn columns(self) -> &'static [Column] {
+        match self {
+            TableIndex::Assembly => columns![
+                ("HashAlgId", Integer),
+                ("Version", Integer),
+                ("Flags", Integer),
+                ("PublicKey", Blob),
+                ("Name", String),
+                ("Culture", String),
+            ],
+            TableIndex::AssemblyOs => columns![
+                ("OSPlatformID", Integer),
+                ("OSMajorVersion", Integer),
+                ("OSMinorVersion", Integer),
+            ],
+            TableIndex::AssemblyProcessor => columns![("Processor", Integer)],
+            TableIndex::AssemblyRef => columns![
+                ("Version", Integer),
+                ("Flags", Integer),
+                ("PublicKeyOrToken", Blob),
+                ("Name", String),
+                ("Culture", String),
+                ("HashValue", Blob),
+            ],
+            TableIndex::AssemblyRefOs => columns![
+                ("OSPlatformID", Integer),
+                ("OSMajorVersion", Integer),
+                ("OSMinorVersion", Integer),
+                ("AssemblyRef", Table(TableIndex::AssemblyRef)),
+            ],
+            TableIndex::AssemblyRefProcessor => columns![
+                ("Processor", Integer),
+                ("AssemblyRef", Table(TableIndex::AssemblyRef)),
+            ],
+            TableIndex::ClassLayout => columns![
+                ("PackingSize", Integer),
+                ("ClassSize", Integer),
+                ("Parent", Table(TableIndex::TypeDef)),
+            ],
+            TableIndex::Constant => columns![
+                ("Type", Integer),
+                ("Parent", Coded(HasConstant)),
+                ("Value", Blob),
+            ],
+            TableIndex::CustomAttribute => columns![
+                ("Parent", Coded(HasCustomAttribute)),
+                ("Type", Coded(CustomAttributeType)),
+                ("Value", Blob),
+            ],
+            TableIndex::DeclSecurity => columns![
+                ("Action", Integer),
+                ("Parent", Coded(HasDeclSecurity)),
+                ("PermissionSet", Blob),
+            ],
+            TableIndex::Event => columns![
+                ("EventFlags", Integer),
+                ("Name", String),
+                ("EventType", Coded(TypeDefOrRef)),
+            ],
+            TableIndex::EventMap => columns![
+                ("Parent", Table(TableIndex::TypeDef)),
+                ("EventList", Table(TableIndex::Event)),
+            ],
+            TableIndex::ExportedType => columns![
+                ("Flags", Integer),
+                ("TypeDefId", Integer),
+                ("TypeName", String),
+                ("TypeNamespace", String),
+                ("Implementation", Coded(Implementation)),
+            ],
+            TableIndex::Field => {
+                columns![("Flags", Integer), ("Name", String), ("Signature", Blob),]
+            }
+            TableIndex::FieldLayout => {
+                columns![("Offset", Integer), ("Field", Table(TableIndex::Field)),]
+            }
+            TableIndex::FieldMarshal => {
+                columns![("Parent", Coded(HasFieldMarshal)), ("NativeType", Blob),]
+            }
+            TableIndex::FieldRva => {
+                columns![("RVA", Integer), ("Field", Table(TableIndex::Field)),]
+            }
+            TableIndex::File => {
+                columns![("Flags", Integer), ("Name", String), ("HashValue", Blob),]
+            }
+            TableIndex::GenericParam => columns![
+                ("Number", Integer),
+                ("Flags", Integer),
+                ("Owner", Coded(TypeOrMethodDef)),
+                ("Name", String),
+            ],
+            TableIndex::GenericParamConstraint => columns![
+                ("Owner", Table(TableIndex::GenericParam)),
+                ("Constraint", Coded(TypeDefOrRef)),
+            ],
+            TableIndex::ImplMap => columns![
+                ("MappingFlags", Integer),
+                ("MemberForwarded", Coded(MemberForwarded)),
+                ("ImportName", String),
+                ("ImportScope", Table(TableIndex::ModuleRef)),
+            ],
+            TableIndex::InterfaceImpl => columns![
+                ("Class", Table(TableIndex::TypeDef)),
+                ("Interface", Coded(TypeDefOrRef)),
+            ],
+            TableIndex::ManifestResource => columns![
+                ("Offset", Integer),
+                ("Flags", Integer),
+                ("Name", String),
+                ("Implementation", Coded(Implementation)),
+            ],
+            TableIndex::MemberRef => columns![
+                ("Class", Coded(MemberRefParent)),
+                ("Name", String),
+                ("Signature", Blob),
+            ],
+            TableIndex::MethodDef => columns![
+                ("RVA", Integer),
+                ("ImplFlags", Integer),
+                ("Flags", Integer),
+                ("Name", String),
+                ("Signature", Blob),
+                ("ParamList", Table(TableIndex::Param)),
+            ],
+            TableIndex::MethodImpl => columns![
+                ("Class", Table(TableIndex::TypeDef)),
+                ("MethodBody", Coded(MethodDefOrRef)),
+                ("MethodDeclaration", Coded(MethodDefOrRef)),
+            ],
+            TableIndex::MethodSemantics => columns![
+                ("Semantics", Integer),
+                ("Method", Table(TableIndex::MethodDef)),
+                ("Association", Coded(HasSemantics)),
+            ],
+            TableIndex::MethodSpec => {
+                columns![("Method", Coded(MethodDefOrRef)), ("Instantiation", Blob),]
+            }
+            TableIndex::Module => columns![
+                ("Generation", Integer),
+                ("Name", String),
+                ("Mvid", Guid),
+                ("EncId", Guid),
+                ("EncBaseId", Guid),
+            ],
+            TableIndex::ModuleRef => columns![("Name", String)],
+            TableIndex::NestedClass => columns![
+                ("NestedClass", Table(TableIndex::TypeDef)),
+                ("EnclosingClass", Table(TableIndex::TypeDef)),
+            ],
+            TableIndex::Param => {
+                columns![("Flags", Integer), ("Sequence", Integer), ("Name", String),]
+            }
+            TableIndex::Property => columns![("Flags", Integer), ("Name", String), ("Type", Blob),],
+            TableIndex::PropertyMap => columns![
+                ("Parent", Table(TableIndex::TypeDef)),
+                ("PropertyList", Table(TableIndex::Property)),
+            ],
+            TableIndex::StandaloneSig => columns![("Signature", Blob)],
+            TableIndex::TypeDef => columns![
+                ("Flags", Integer),
+                ("TypeName", String),
+                ("TypeNamespace", String),
+                ("Extends", Coded(TypeDefOrRef)),
+                ("FieldList", Table(TableIndex::Field)),
+                ("MethodList", Table(TableIndex::MethodDef)),
+            ],
+            TableIndex::TypeRef => columns![
+                ("ResolutionScope", Coded(ResolutionScope)),
+                ("TypeName", String),
+                ("TypeNamespace", String),
+            ],
+            TableIndex::TypeSpec => columns![("Signature", Blob)],
+        }
+    }
+}
+
+impl CodedIndex {
+    /// The table for each tag of the coded index, as defined by ECMA-335 II.24.2.6, or `None`
+    /// for unused tags
+    pub fn tables(self) -> &'static [Option<TableIndex>] {
+        use TableIndex as T;
+
+        match self {
+            TypeDefOrRef => &[Some(T::TypeDef), Some(T::TypeRef), Some(T::TypeSpec)],
+            HasConstant => &[Some(T::Field), Some(T::Param), Some(T::Property)],
+            HasCustomAttribute => &[
+                Some(T::MethodDef),
+                Some(T::Field),
+                Some(T::TypeRef),
+                Some(T::TypeDef),
+                Some(T::Param),
+                Some(T::InterfaceImpl),
+                Some(T::MemberRef),
+                Some(T::Module),
+                Some(T::DeclSecurity),
+                Some(T::Property),
+                Some(T::Event),
+                Some(T::StandaloneSig),
+                Some(T::ModuleRef),
+                Some(T::TypeSpec),
+                Some(T::Assembly),
+                Some(T::AssemblyRef),
+                Some(T::File),
+                Some(T::ExportedType),
+                Some(T::ManifestResource),
+                Some(T::GenericParam),
+                Some(T::GenericParamConstraint),
+                Some(T::MethodSpec),
+            ],
+            HasFieldMarshal => &[Some(T::Field), Some(T::Param)],
+            HasDeclSecurity => &[Some(T::TypeDef), Some(T::MethodDef), Some(T::Assembly)],
+            MemberRefParent => &[
+                Some(T::TypeDef),
+                Some(T::TypeRef),
+                Some(T::ModuleRef),
+                Some(T::MethodDef),
+                Some(T::TypeSpec),
+            ],
+            HasSemantics => &[Some(T::Event), Some(T::Property)],
+            MethodDefOrRef => &[Some(T::MethodDef), Some(T::MemberRef)],
+            MemberForwarded => &[Some(T::Field), Some(T::MethodDef)],
+            Implementation => &[Some(T::File), Some(T::AssemblyRef), Some(T::ExportedType)],
+            CustomAttributeType => &[None, None, Some(T::MethodDef), Some(T::MemberRef), None],
+            ResolutionScope => &[
+                Some(T::Module),
+                Some(T::ModuleRef),
+                Some(T::AssemblyRef),
+                Some(T::TypeRef),
+            ],
+            TypeOrMethodDef => &[Some(T::TypeDef), Some(T::MethodDef)],
+        }
+    }
+
+    /// The number of low bits of the coded index that hold the tag
+    pub fn tag_bits(self) -> u32 {
+        let tags = self.tables().len() as u32;
+        32 - (tags - 1).leading_zeros()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file::TABLE_COUNT;
+    use crate::{File, TableIndex};
+
+    #[test]
+    fn ids_round_trip() {
+        let tables: Vec<TableIndex> = (0..64).filter_map(TableIndex::from_id).collect();
+        assert_eq!(tables.len(), TABLE_COUNT);
+
+        for table in tables {
+            assert_eq!(TableIndex::from_id(table.id()), Some(table));
+        }
+    }
+
+    #[test]
+    fn coded_tags_match_encoding() {
+        use crate::*;
+
+        let event = HasAttribute::Event(Event(Row::new(4, TableIndex::Event, 0))).encode();
+        let bits = CodedIndex::HasCustomAttribute.tag_bits();
+        assert_eq!(bits, 5);
+        assert_eq!(event >> bits, 5);
+        assert_eq!(
+            CodedIndex::HasCustomAttribute.tables()[(event & 0x1f) as usize],
+            Some(TableIndex::Event)
+        );
+
+        let member_ref = AttributeType::MemberRef(MemberRef(Row::new(0, TableIndex::MemberRef, 0)));
+        let bits = CodedIndex::CustomAttributeType.tag_bits();
+        assert_eq!(bits, 3);
+        assert_eq!(
+            CodedIndex::CustomAttributeType.tables()[(member_ref.encode() & 0x7) as usize],
+            Some(TableIndex::MemberRef)
+        );
+    }
+
+    #[test]
+    fn columns_match_layout() {
+        let file = File::try_new("winmds/Windows.Foundation.FoundationContract.winmd").unwrap();
+
+        for table in (0..64).filter_map(TableIndex::from_id) {
+            let data = &file.tables[table as usize];
+            let sizes = data.columns.iter().filter(|(_, size)| *size != 0).count();
+            assert_eq!(sizes, table.columns().len(), "{:?}", table);
+        }
+    }
+}
@@ -8,12 +8,20 @@
 ///
 /// A `u32` is a `Pod` because any [u8; 4] can be viewed as a `u32` safely.
 /// A `bool` is _not_ a `Pod` because it must either be a `0` or `1` in memory
+///
+/// # Safety
+///
+/// Implementors must guarantee that every bit pattern is a valid value of the type.
 pub(crate) unsafe trait Pod {}
 
 /// A Pod type that is also safe to copy.
 ///
 /// In addition to the same safety properties as Pod types, this type must be able to
 /// be zeroed, many that it is valid to represent this type in memory as all 0s.
+///
+/// # Safety
+///
+/// Implementors must uphold the same guarantees as [`Pod`] and be valid when zeroed.
 pub(crate) unsafe trait CopyPod: Copy {}
 
 unsafe impl CopyPod for u8 {}
@@ -26,10 +34,11 @@ unsafe impl CopyPod for i32 {}
 unsafe impl CopyPod for i64 {}
 
 pub(crate) trait View {
-    fn view_as<T: Pod>(&self, cli_offset: u32) -> &T;
-    fn view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> &[T];
     fn copy_as<T: Copy + CopyPod>(&self, cli_offset: u32) -> T;
-    fn view_as_str(&self, cli_offset: u32) -> &[u8];
+    fn try_view_as<T: Pod>(&self, cli_offset: u32) -> Option<&T>;
+    fn try_view_as_slice_of<T: Pod>(&self, cli_offset: u32, len: u32) -> Option<&[T]>;
+    fn try_copy_as<T: Copy + CopyPod>(&self, cli_offset: u32) -> Option<T>;
+    fn try_view_as_str(&self, cli_offset: u32) -> Option<&[u8]>;
 }
 
 pub trait Decode {
@@ -1,7 +1,7 @@
 use crate::{
     parsed::*,
     traits::{Decode, View},
-    File, TableIndex,
+    Error, File, TableIndex,
 };
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
@@ -17,29 +17,59 @@ pub struct TypeReader {
     /// that namespace. The keys are the namespace and the values is a mapping
     /// of type names to type definitions
     pub types: BTreeMap<String, BTreeMap<String, TypeDef>>,
+    /// Nested types known to this [`TypeReader`]
+    ///
+    /// This is a mapping between enclosing types and the types nested directly
+    /// inside them. Nested types are not included in [`TypeReader::types`].
+    pub nested: BTreeMap<TypeDef, BTreeMap<String, TypeDef>>,
+    /// The PropertyMap row of each type that has properties. The PropertyMap table is
+    /// not required to be sorted, so it is indexed once rather than searched.
+    pub(crate) property_maps: BTreeMap<TypeDef, Row>,
+    /// The EventMap row of each type that has events, indexed for the same reason.
+    pub(crate) event_maps: BTreeMap<TypeDef, Row>,
+    /// The MethodSemantics row of each accessor method. The MethodSemantics table is sorted
+    /// by association rather than by method.
+    pub(crate) method_semantics: BTreeMap<MethodDef, MethodSemantics>,
+    /// The type definitions left out of [`TypeReader::types`] by namespace and name, such as
+    /// the attribute types in WinRT metadata
+    pub(crate) excluded_types: BTreeMap<String, BTreeMap<String, TypeDef>>,
+    /// The nested type definitions left out of [`TypeReader::nested`] by enclosing type and name
+    pub(crate) excluded_nested: BTreeMap<TypeDef, BTreeMap<String, TypeDef>>,
 }
 
 impl TypeReader {
     /// Access the windows metadata data directory on Windows.
     ///
-    /// This is set in the `windir` environment variable
+    /// This is set in the `windir` environment variable, falling back to `C:\Windows`
     ///
     /// # Panics
     ///
-    /// This function panics if the `windir` environment variable is set or if the files
-    /// where the windows metadata is stored cannot be read.
+    /// This function panics if the files where the windows metadata is stored cannot be read.
     pub fn from_os() -> Self {
-        let dir = std::env::var("windir").expect("No `windir` environent variable set");
-        let mut dir = PathBuf::from(dir);
+        Self::try_from_os().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Access the windows metadata data directory on Windows.
+    ///
+    /// This is set in the `windir` environment variable, falling back to `C:\Windows`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] naming the metadata directory if the files where the windows
+    /// metadata is stored cannot be read.
+    pub fn try_from_os() -> Result<Self, Error> {
+        let mut dir = std::env::var_os("windir")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(r"C:\Windows"));
         dir.push(SYSTEM32);
         dir.push("winmetadata");
 
-        let files = std::fs::read_dir(dir)
-            .expect("Could not read Windowws metadata path")
+        let files = std::fs::read_dir(&dir)
+            .map_err(|error| Error::Io { path: dir, error })?
             .filter_map(|value| value.ok())
             .map(|value| value.path());
 
-        Self::from_iter(files)
+        Self::try_from_iter(files)
     }
 
     /// Insert win32 metadata at a given path
@@ -48,15 +78,30 @@ impl TypeReader {
     ///
     /// This function panics if the if the file where the windows metadata is stored cannot be read.
     pub fn from_win32<P: AsRef<Path>>(file: P) -> Self {
+        Self::try_from_win32(file).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Insert win32 metadata at a given path
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the file where the windows metadata is stored cannot be read.
+    pub fn try_from_win32<P: AsRef<Path>>(file: P) -> Result<Self, Error> {
         let mut reader = Self {
             files: Vec::default(),
             types: BTreeMap::default(),
+            nested: BTreeMap::default(),
+            property_maps: BTreeMap::default(),
+            event_maps: BTreeMap::default(),
+            method_semantics: BTreeMap::default(),
+            excluded_types: BTreeMap::default(),
+            excluded_nested: BTreeMap::default(),
         };
 
-        let file = File::new(file);
+        let file = File::try_new(file)?;
         reader.insert_file_at_index(file, 0, InsertMode::All);
 
-        reader
+        Ok(reader)
     }
 
     /// Insert WinRT metadata at the given paths
@@ -65,33 +110,93 @@ impl TypeReader {
     ///
     /// This function panics if the if the files where the windows metadata are stored cannot be read.
     pub fn from_iter<I: IntoIterator<Item = PathBuf>>(files: I) -> Self {
+        Self::try_from_iter(files).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Insert WinRT metadata at the given paths
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] for the first file where the windows metadata cannot be read.
+    pub fn try_from_iter<I: IntoIterator<Item = PathBuf>>(files: I) -> Result<Self, Error> {
         let mut reader = Self {
             files: Vec::default(),
             types: BTreeMap::default(),
+            nested: BTreeMap::default(),
+            property_maps: BTreeMap::default(),
+            event_maps: BTreeMap::default(),
+            method_semantics: BTreeMap::default(),
+            excluded_types: BTreeMap::default(),
+            excluded_nested: BTreeMap::default(),
         };
         for (file_index, file) in files.into_iter().enumerate() {
-            let file = File::new(file);
+            let file = File::try_new(file)?;
             reader.insert_file_at_index(file, file_index, InsertMode::WinrtOnly);
         }
-        reader
+        Ok(reader)
+    }
+
+    /// Inserts a hand-built file after the reader's other files, returning its index
+    #[cfg(test)]
+    pub(crate) fn insert_test_file(&mut self, file: File) -> u16 {
+        let file_index = self.files.len();
+        self.insert_file_at_index(file, file_index, InsertMode::All);
+        file_index as u16
     }
 
     fn insert_file_at_index(&mut self, file: File, file_index: usize, insert_mode: InsertMode) {
         let row_count = file.type_def_table().row_count;
         self.files.push(file);
 
+        for map in self.rows(file_index as u16, TableIndex::PropertyMap) {
+            let parent = TypeDef(Row::new(
+                self.u32(map, 0) - 1,
+                TableIndex::TypeDef,
+                file_index as u16,
+            ));
+            self.property_maps.insert(parent, map);
+        }
+
+        for map in self.rows(file_index as u16, TableIndex::EventMap) {
+            let parent = TypeDef(Row::new(
+                self.u32(map, 0) - 1,
+                TableIndex::TypeDef,
+                file_index as u16,
+            ));
+            self.event_maps.insert(parent, map);
+        }
+
+        for row in self.rows(file_index as u16, TableIndex::MethodSemantics) {
+            let semantics = MethodSemantics(row);
+            self.method_semantics
+                .entry(semantics.method(self))
+                .or_insert(semantics);
+        }
+
         for row in 0..row_count {
             let def = TypeDef(Row::new(row, TableIndex::TypeDef, file_index as u16));
+            let enclosing = def.enclosing_type(self);
+            let (namespace, name) = def.name(self);
+            let namespace = namespace.to_string();
+            let name = name.to_string();
+
+            let (types, nested) = if insert_mode == InsertMode::WinrtOnly && !def.is_winrt(self) {
+                (&mut self.excluded_types, &mut self.excluded_nested)
+            } else {
+                (&mut self.types, &mut self.nested)
+            };
+
+            if let Some(enclosing) = enclosing {
+                nested
+                    .entry(enclosing)
+                    .or_default()
+                    .entry(name)
+                    .or_insert(def);
 
-            if insert_mode == InsertMode::WinrtOnly && !def.is_winrt(&self) {
                 continue;
             }
 
-            let (namespace, name) = def.name(&self);
-            let namespace = namespace.to_string();
-            let name = name.to_string();
-
-            self.types
+            types
                 .entry(namespace)
                 .or_default()
                 .entry(name)
@@ -119,25 +224,95 @@ impl TypeReader {
     ///
     /// Panics if no type definition for the given namespace and type name can be found
     pub fn resolve_type_def(&self, (namespace, type_name): (&str, &str)) -> TypeDef {
-        if let Some(types) = self.types.get(namespace) {
-            if let Some(def) = types.get(type_name) {
-                return *def;
-            }
+        match self.find_type_def((namespace, type_name)) {
+            Some(def) => def,
+            None => panic!("Could not find type `{}.{}`", namespace, type_name),
         }
+    }
+
+    /// Find a type definition given its namespace and type name
+    pub fn find_type_def(&self, (namespace, type_name): (&str, &str)) -> Option<TypeDef> {
+        self.types.get(namespace)?.get(type_name).copied()
+    }
+
+    /// Resolve a nested type definition given its enclosing type and type name
+    ///
+    /// # Panics
+    ///
+    /// Panics if no type definition with the given name is nested inside the enclosing type
+    pub fn resolve_nested_type_def(&self, enclosing: TypeDef, type_name: &str) -> TypeDef {
+        if let Some(def) = self.find_nested_type_def(enclosing, type_name) {
+            return def;
+        }
+
+        let (namespace, name) = enclosing.name(self);
+        panic!(
+            "Could not find nested type `{}` in `{}.{}`",
+            type_name, namespace, name
+        );
+    }
+
+    /// Find a nested type definition given its enclosing type and type name
+    pub fn find_nested_type_def(&self, enclosing: TypeDef, type_name: &str) -> Option<TypeDef> {
+        self.nested.get(&enclosing)?.get(type_name).copied()
+    }
+
+    /// Like [`TypeReader::find_type_def`] but also finds types left out of the reader's
+    /// namespaces, such as the attribute types in WinRT metadata.
+    pub(crate) fn find_any_type_def(&self, name: (&str, &str)) -> Option<TypeDef> {
+        self.find_type_def(name)
+            .or_else(|| self.excluded_types.get(name.0)?.get(name.1).copied())
+    }
+
+    /// Like [`TypeReader::find_nested_type_def`] but also finds types left out of the reader's
+    /// namespaces.
+    pub(crate) fn find_any_nested_type_def(
+        &self,
+        enclosing: TypeDef,
+        type_name: &str,
+    ) -> Option<TypeDef> {
+        self.find_nested_type_def(enclosing, type_name).or_else(|| {
+            self.excluded_nested
+                .get(&enclosing)?
+                .get(type_name)
+                .copied()
+        })
+    }
 
-        panic!("Could not find type `{}.{}`", namespace, type_name);
+    /// Get the [`Module`] describing the file at the given index
+    ///
+    /// Returns `None` if there is no file at the index or the file has no Module row.
+    pub fn module(&self, file: u16) -> Option<Module> {
+        self.files.get(file as usize)?;
+        self.rows(file, TableIndex::Module).next().map(Module)
+    }
+
+    /// Get the [`Assembly`] described by the file at the given index, if the file has an assembly manifest
+    pub fn assembly(&self, file: u16) -> Option<Assembly> {
+        self.files.get(file as usize)?;
+        self.rows(file, TableIndex::Assembly).next().map(Assembly)
+    }
+
+    /// Get the assemblies referenced by the file at the given index
+    pub fn assembly_refs(&self, file: u16) -> impl Iterator<Item = AssemblyRef> {
+        self.rows(file, TableIndex::AssemblyRef).map(AssemblyRef)
     }
 
     /// Read a [`u32`] value from a specific [`Row`] and column
     pub fn u32(&self, row: Row, column: u32) -> u32 {
+        self.u64(row, column) as u32
+    }
+
+    /// Read a [`u64`] value from a specific [`Row`] and column
+    pub fn u64(&self, row: Row, column: u32) -> u64 {
         let file = &self.files[row.file_index as usize];
         let table = &file.tables[row.table_index as usize];
         let offset = table.data + row.index * table.row_size + table.columns[column as usize].0;
         match table.columns[column as usize].1 {
-            1 => file.bytes.copy_as::<u8>(offset) as u32,
-            2 => file.bytes.copy_as::<u16>(offset) as u32,
-            4 => file.bytes.copy_as::<u32>(offset) as u32,
-            _ => file.bytes.copy_as::<u64>(offset) as u32,
+            1 => file.bytes.copy_as::<u8>(offset) as u64,
+            2 => file.bytes.copy_as::<u16>(offset) as u64,
+            4 => file.bytes.copy_as::<u32>(offset) as u64,
+            _ => file.bytes.copy_as::<u64>(offset),
         }
     }
 
@@ -152,6 +327,74 @@ impl TypeReader {
         std::str::from_utf8(&file.bytes[offset..offset + last]).unwrap()
     }
 
+    /// Read a [`Guid`] value from a specific [`Row`] and column
+    ///
+    /// A null index into the guid heap produces an all-zero [`Guid`].
+    pub fn guid(&self, row: Row, column: u32) -> Guid {
+        let file = &self.files[row.file_index as usize];
+        let index = self.u32(row, column);
+
+        if index == 0 {
+            return Guid::default();
+        }
+
+        let offset = (file.guids + (index - 1) * 16) as usize;
+        Guid::from_bytes(&file.bytes[offset..offset + 16])
+    }
+
+    /// Read the string literal at the given offset of the `#US` heap of a file
+    ///
+    /// Returns `None` if the offset or the string's length is outside of the heap.
+    pub fn user_string(&self, file: u16, index: u32) -> Option<UserString> {
+        let heap = self.user_string_heap(file).get(index as usize..)?;
+        let (len, len_bytes) = user_string_len(heap)?;
+        let bytes = heap.get(len_bytes..len_bytes + len as usize)?;
+
+        // The last byte is a flag rather than part of the UTF-16 data.
+        let (chars, special) = match bytes.split_last() {
+            Some((flag, chars)) => (chars, *flag == 1),
+            None => (bytes, false),
+        };
+
+        let chars: Vec<u16> = chars
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+
+        Some(UserString {
+            index,
+            value: String::from_utf16_lossy(&chars),
+            special,
+        })
+    }
+
+    /// Get all of the non-empty string literals in the `#US` heap of a file
+    ///
+    /// The iteration stops early if a string's length is outside of the heap.
+    pub fn user_strings(&self, file: u16) -> impl Iterator<Item = UserString> + '_ {
+        let heap = self.user_string_heap(file);
+        let mut index = 0;
+
+        std::iter::from_fn(move || {
+            while index < heap.len() {
+                let current = index;
+                let (len, len_bytes) = user_string_len(&heap[index..])?;
+                index += len_bytes + len as usize;
+
+                if len != 0 {
+                    return self.user_string(file, current as u32);
+                }
+            }
+            None
+        })
+    }
+
+    fn user_string_heap(&self, file: u16) -> &[u8] {
+        let file = &self.files[file as usize];
+        let offset = file.user_strings as usize;
+        &file.bytes[offset..offset + file.user_strings_size as usize]
+    }
+
     /// Read a `T: Decode` value from a specific [`Row`] and column
     pub(crate) fn decode<T: Decode>(&self, row: Row, column: u32) -> T {
         T::decode(self.u32(row, column), row.file_index)
@@ -175,21 +418,50 @@ impl TypeReader {
         (first..last).map(move |value| Row::new(value, table, row.file_index))
     }
 
+    /// Get the tables present in the file at the given index, ordered by their ECMA-335 id
+    ///
+    /// Tables without any rows are skipped, even if the file's table stream lists them.
+    pub fn tables(&self, file: u16) -> impl Iterator<Item = TableIndex> + '_ {
+        (0..64)
+            .filter_map(TableIndex::from_id)
+            .filter(move |table| self.row_count(file, *table) != 0)
+    }
+
+    /// Get the number of rows in a table of the file at the given index
+    pub fn row_count(&self, file: u16, table: TableIndex) -> u32 {
+        self.files[file as usize].tables[table as usize].row_count
+    }
+
+    /// Get all the rows of a table of the file at the given index
+    pub fn rows(&self, file: u16, table: TableIndex) -> impl Iterator<Item = Row> {
+        let row_count = self.row_count(file, table);
+        (0..row_count).map(move |row| Row::new(row, table, file))
+    }
+
+    /// Read the raw values of every column of a specific [`Row`]
+    ///
+    /// The meaning of each value is described by [`TableIndex::columns`].
+    pub fn values(&self, row: Row) -> Vec<u64> {
+        (0..row.table_index.columns().len() as u32)
+            .map(|column| self.u64(row, column))
+            .collect()
+    }
+
     /// Read a blob for a given row and column
-    pub fn blob(&self, row: Row, column: u32) -> Blob {
+    pub fn blob(&self, row: Row, column: u32) -> Blob<'_> {
         let file = &self.files[row.file_index as usize];
         let offset = (file.blobs + self.u32(row, column)) as usize;
         let initial_byte = file.bytes[offset];
         let (mut blob_size, blob_size_bytes) = match initial_byte >> 5 {
-            0..=3 => (initial_byte & 0x7f, 1),
-            4..=5 => (initial_byte & 0x3f, 2),
-            6 => (initial_byte & 0x1f, 4),
+            0..=3 => ((initial_byte & 0x7f) as usize, 1),
+            4..=5 => ((initial_byte & 0x3f) as usize, 2),
+            6 => ((initial_byte & 0x1f) as usize, 4),
             _ => panic!("Invalid blob size"),
         };
         for byte in &file.bytes[offset + 1..offset + blob_size_bytes] {
-            blob_size = blob_size.checked_shl(8).unwrap_or(0) + byte;
+            blob_size = (blob_size << 8) + *byte as usize;
         }
-        Blob::new(self, row.file_index, offset + blob_size_bytes)
+        Blob::new(self, row.file_index, offset + blob_size_bytes, blob_size)
     }
 
     pub(crate) fn equal_range(
@@ -311,6 +583,24 @@ impl TypeReader {
     }
 }
 
+/// Read the compressed length that starts a `#US` heap entry, returning the length and the
+/// number of bytes used to encode it.
+fn user_string_len(bytes: &[u8]) -> Option<(u32, usize)> {
+    let first = *bytes.first()? as u32;
+
+    if first & 0x80 == 0 {
+        Some((first, 1))
+    } else if first & 0xC0 == 0x80 {
+        Some(((first & 0x3F) << 8 | *bytes.get(1)? as u32, 2))
+    } else {
+        let rest = bytes.get(1..4)?;
+        Some((
+            (first & 0x1F) << 24 | (rest[0] as u32) << 16 | (rest[1] as u32) << 8 | rest[2] as u32,
+            4,
+        ))
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum InsertMode {
     All,
@@ -0,0 +1,22 @@
+#[test]
+fn assembly() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let module = reader.module(0).unwrap();
+    assert!(module.name(reader) == "Windows.Foundation.FoundationContract.winmd");
+
+    let assembly = reader.assembly(0).unwrap();
+    assert!(assembly.name(reader) == "Windows.Foundation.FoundationContract");
+    assert!(assembly.version(reader).to_string() == "4.0.0.0");
+    assert!(assembly.culture(reader).is_empty());
+    assert!(assembly.flags(reader).windows_runtime());
+
+    let refs: Vec<winmd::parsed::AssemblyRef> = reader.assembly_refs(0).collect();
+    assert!(refs.len() == 1);
+    assert!(refs[0].name(reader) == "mscorlib");
+    assert!(refs[0].version(reader).major == 255);
+
+    assert!(reader.module(1).is_none());
+    assert!(reader.assembly(1).is_none());
+}
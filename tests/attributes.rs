@@ -0,0 +1,61 @@
+use winmd::parsed::*;
+
+#[test]
+fn attributes() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "Deferral"));
+
+    let attribute = def.attribute(
+        reader,
+        ("Windows.Foundation.Metadata", "DualApiPartitionAttribute"),
+    );
+    let args = attribute.args(reader).unwrap();
+    assert!(args.len() == 1);
+    assert!(args[0].0 == "version");
+    assert!(args[0].1 == AttributeArg::U32(0x0A00_0000));
+
+    let attribute = def.attribute(
+        reader,
+        ("Windows.Foundation.Metadata", "ContractVersionAttribute"),
+    );
+    let args = attribute.args(reader).unwrap();
+    assert!(args.len() == 2);
+
+    match args[0].1 {
+        AttributeArg::TypeDef(contract) => {
+            assert!(contract.name(reader) == ("Windows.Foundation", "FoundationContract"))
+        }
+        _ => panic!("Expected a TypeDef"),
+    }
+
+    assert!(args[1].1 == AttributeArg::U32(0x0001_0000));
+    assert!(args[0].0 == "contract");
+    assert!(args[1].0 == "version");
+
+    let attribute = def.attribute(
+        reader,
+        ("Windows.Foundation.Metadata", "MarshalingBehaviorAttribute"),
+    );
+    let args = attribute.args(reader).unwrap();
+    assert!(args[0].0 == "behavior");
+    assert!(args[0].1.enum_name(reader) == Some("Agile"));
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "PropertyValue"));
+    let attribute = def.attribute(
+        reader,
+        ("Windows.Foundation.Metadata", "ThreadingAttribute"),
+    );
+    let args = attribute.args(reader).unwrap();
+    assert!(args[0].0 == "model");
+    assert!(args[0].1.enum_name(reader) == Some("Both"));
+
+    match &args[0].1 {
+        AttributeArg::Enum(def, value) => {
+            assert!(def.name(reader) == ("Windows.Foundation.Metadata", "ThreadingModel"));
+            assert!(*value == ConstantValue::I32(3));
+        }
+        _ => panic!("Expected an enum"),
+    }
+}
@@ -0,0 +1,26 @@
+use winmd::parsed::*;
+
+#[test]
+fn constants() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "AsyncStatus"));
+    assert!(def.underlying_type(reader) == ElementType::I32);
+
+    let values: Vec<(&str, ConstantValue)> = def
+        .fields(reader)
+        .filter_map(|field| {
+            field
+                .constants(reader)
+                .next()
+                .map(|constant| (field.name(reader), constant.decode(reader)))
+        })
+        .collect();
+
+    assert!(values.len() == 4);
+    assert!(values.contains(&("Started", ConstantValue::I32(0))));
+    assert!(values.contains(&("Completed", ConstantValue::I32(1))));
+    assert!(values.contains(&("Canceled", ConstantValue::I32(2))));
+    assert!(values.contains(&("Error", ConstantValue::I32(3))));
+}
@@ -0,0 +1,67 @@
+use winmd::parsed::*;
+
+fn method(reader: &winmd::TypeReader, type_name: (&str, &str), method_name: &str) -> MethodDef {
+    reader
+        .resolve_type_def(type_name)
+        .methods(reader)
+        .find(|method| method.name(reader) == method_name)
+        .unwrap()
+}
+
+#[test]
+fn element_types() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let sig = method(
+        reader,
+        ("Windows.Foundation.Collections", "IIterable`1"),
+        "First",
+    )
+    .signature(reader);
+
+    match sig.return_type.element_type {
+        ElementType::GenericInst(instance) => {
+            assert!(
+                instance.generic_type.name(reader)
+                    == Some(("Windows.Foundation.Collections", "IIterator`1"))
+            );
+            assert!(!instance.value_type);
+            assert!(instance.args == vec![ElementType::Var(0)]);
+        }
+        _ => panic!("Expected a generic instance"),
+    }
+
+    let sig = method(
+        reader,
+        ("Windows.Foundation.Collections", "IIterator`1"),
+        "GetMany",
+    )
+    .signature(reader);
+    assert!(sig.return_type.element_type == ElementType::U32);
+    assert!(
+        sig.params[0].sig_type.element_type
+            == ElementType::SZArray(Vec::new(), Box::new(ElementType::Var(0)))
+    );
+
+    let sig = method(
+        reader,
+        ("Windows.Foundation", "IPropertyValue"),
+        "GetUInt8Array",
+    )
+    .signature(reader);
+    assert!(sig.params[0].sig_type.by_ref);
+    assert!(
+        sig.params[0].sig_type.element_type
+            == ElementType::SZArray(Vec::new(), Box::new(ElementType::U8))
+    );
+
+    let sig = method(
+        reader,
+        ("Windows.Foundation", "IPropertyValueStatics"),
+        "CreateInspectable",
+    )
+    .signature(reader);
+    assert!(sig.return_type.element_type == ElementType::Object);
+    assert!(sig.params[0].sig_type.element_type == ElementType::Object);
+}
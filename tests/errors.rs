@@ -0,0 +1,72 @@
+use std::convert::TryInto;
+use std::path::PathBuf;
+use winmd::{Error, File, TypeReader};
+
+fn write_temp(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("winmd-errors-{}-{}", std::process::id(), name));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn errors() {
+    let path = PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let bytes = std::fs::read(&path).unwrap();
+    assert!(File::try_new(&path).is_ok());
+    assert!(TypeReader::try_from_iter(vec![path.clone()]).is_ok());
+
+    let missing = PathBuf::from("winmds/Missing.winmd");
+    match File::try_new(&missing) {
+        Err(Error::Io { path, .. }) => assert!(path == missing),
+        _ => panic!("Expected an I/O error"),
+    }
+
+    let error = TypeReader::try_from_iter(vec![path.clone(), missing.clone()])
+        .err()
+        .unwrap();
+    assert!(error.path() == missing);
+    assert!(error.to_string().contains("Missing.winmd"));
+
+    match File::try_new("Cargo.toml") {
+        Err(Error::NotPe { offset, .. }) => assert!(offset == 0),
+        _ => panic!("Expected a NotPe error"),
+    }
+
+    // Clear the CLR header's entry in the optional header's data directory.
+    let mut no_clr_header = bytes.clone();
+    let pe = u32::from_le_bytes(bytes[0x3C..0x40].try_into().unwrap()) as usize;
+    let magic = u16::from_le_bytes(bytes[pe + 24..pe + 26].try_into().unwrap());
+    let data_directory = pe + 24 + if magic == 0x10B { 96 } else { 112 };
+    no_clr_header[data_directory + 14 * 8..data_directory + 15 * 8].fill(0);
+    let no_clr_header = write_temp("no-clr-header.winmd", &no_clr_header);
+
+    match File::try_new(&no_clr_header) {
+        Err(Error::NoClrHeader { path, .. }) => assert!(path == no_clr_header),
+        _ => panic!("Expected a NoClrHeader error"),
+    }
+
+    let truncated = write_temp("truncated.winmd", &bytes[..bytes.len() / 2]);
+
+    match File::try_new(&truncated) {
+        Err(Error::Truncated { offset, .. }) => assert!(offset != 0),
+        _ => panic!("Expected a Truncated error"),
+    }
+
+    // Shrink the #Strings heap to its leading empty string so that the type names point past it.
+    let mut bad_strings = bytes.clone();
+    let name = bytes
+        .windows(9)
+        .position(|name| name == b"#Strings\0")
+        .unwrap();
+    bad_strings[name - 4..name].copy_from_slice(&1u32.to_le_bytes());
+    let bad_strings = write_temp("bad-strings.winmd", &bad_strings);
+
+    match File::try_new(&bad_strings) {
+        Err(Error::BadTable { path, offset }) => assert!(path == bad_strings && offset != 0),
+        _ => panic!("Expected a BadTable error"),
+    }
+
+    std::fs::remove_file(no_clr_header).unwrap();
+    std::fs::remove_file(truncated).unwrap();
+    std::fs::remove_file(bad_strings).unwrap();
+}
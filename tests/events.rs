@@ -0,0 +1,18 @@
+#[test]
+fn events() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IObservableVector`1"));
+    let events: Vec<winmd::parsed::Event> = def.events(reader).collect();
+    assert!(events.len() == 1);
+    assert!(events[0].name(reader) == "VectorChanged");
+
+    match events[0].event_type(reader) {
+        winmd::parsed::TypeDefOrRef::TypeSpec(_) => {}
+        _ => panic!("Expected a generic event handler"),
+    }
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IStringable"));
+    assert!(def.events(reader).next().is_none());
+}
@@ -0,0 +1,34 @@
+use winmd::parsed::*;
+
+#[test]
+fn fields() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "EventRegistrationToken"));
+    let field = def.fields(reader).next().unwrap();
+    assert!(field.name(reader) == "Value");
+
+    let sig = field.signature(reader);
+    assert!(sig.modifiers.is_empty());
+    assert!(!sig.by_ref);
+    assert!(sig.element_type == ElementType::I64);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "AsyncStatus"));
+    let field = def.fields(reader).next().unwrap();
+    assert!(field.name(reader) == "value__");
+    assert!(field.signature(reader).element_type == ElementType::I32);
+
+    match def
+        .fields(reader)
+        .nth(1)
+        .unwrap()
+        .signature(reader)
+        .element_type
+    {
+        ElementType::Struct(value_type) => {
+            assert!(value_type.name(reader) == Some(("Windows.Foundation", "AsyncStatus")))
+        }
+        _ => panic!("Expected a value type"),
+    }
+}
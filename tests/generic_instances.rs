@@ -0,0 +1,57 @@
+use winmd::parsed::*;
+
+#[test]
+fn generic_instances() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IObservableMap`2"));
+    let instance = GenericInstance {
+        generic_type: TypeDefOrRef::TypeDef(def),
+        value_type: false,
+        args: vec![ElementType::String, ElementType::I32],
+    };
+
+    let (event, event_type) = instance.events(reader).next().unwrap();
+    assert!(event.name(reader) == "MapChanged");
+
+    match event_type {
+        ElementType::GenericInst(handler) => {
+            assert!(
+                handler.generic_type.name(reader)
+                    == Some(("Windows.Foundation.Collections", "MapChangedEventHandler`2"))
+            );
+            assert!(handler.args == instance.args);
+        }
+        _ => panic!("Expected a generic instance"),
+    }
+
+    let interfaces = instance.interfaces(reader);
+    assert!(interfaces.len() == 2);
+    assert!(
+        interfaces[0].generic_type.name(reader)
+            == Some(("Windows.Foundation.Collections", "IMap`2"))
+    );
+    assert!(interfaces[0].args == instance.args);
+    assert!(
+        interfaces[1].generic_type.name(reader)
+            == Some(("Windows.Foundation.Collections", "IIterable`1"))
+    );
+
+    match &interfaces[1].args[0] {
+        ElementType::GenericInst(pair) => assert!(pair.args == instance.args),
+        _ => panic!("Expected a generic instance"),
+    }
+
+    let map = &interfaces[0];
+    let (_, sig) = map
+        .methods(reader)
+        .find(|(method, _)| method.name(reader) == "Lookup")
+        .unwrap();
+    assert!(sig.params[0].sig_type.element_type == ElementType::String);
+    assert!(sig.return_type.element_type == ElementType::I32);
+
+    let (property, sig) = map.properties(reader).next().unwrap();
+    assert!(property.name(reader) == "Size");
+    assert!(sig.element_type == ElementType::U32);
+}
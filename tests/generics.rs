@@ -0,0 +1,27 @@
+use winmd::parsed::*;
+
+#[test]
+fn generics() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IMap`2"));
+    let generics: Vec<GenericParam> = def.generics(reader).collect();
+    assert!(generics.len() == 2);
+    assert!(generics[0].name(reader) == "K");
+    assert!(generics[0].number(reader) == 0);
+    assert!(generics[1].name(reader) == "V");
+    assert!(generics[1].number(reader) == 1);
+
+    for generic in generics {
+        assert!(generic.owner(reader) == TypeOrMethodDef::TypeDef(def));
+        assert!(!generic.flags(reader).covariant());
+        assert!(!generic.flags(reader).contravariant());
+        assert!(generic.constraints(reader).next().is_none());
+    }
+
+    for method in def.methods(reader) {
+        assert!(method.generics(reader).next().is_none());
+        assert!(method.specs(reader).next().is_none());
+    }
+}
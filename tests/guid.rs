@@ -0,0 +1,15 @@
+#[test]
+fn guid() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IStringable"));
+    let guid = def.guid(reader).unwrap();
+    assert!(guid.to_string() == "96369F54-8EB6-48F0-ABCE-C1B211E627C3");
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "Point"));
+    assert!(def.guid(reader).is_none());
+
+    let mvid = reader.module(0).unwrap().mvid(reader);
+    assert!(mvid == "C2ED3877-8BC3-42EB-BAB8-C35AE092F078".parse().unwrap());
+}
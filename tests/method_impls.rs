@@ -0,0 +1,30 @@
+use winmd::parsed::*;
+
+#[test]
+fn method_impls() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "Deferral"));
+    let impls: Vec<MethodImpl> = def.method_impls(reader).collect();
+    assert!(impls.len() == 2);
+    assert!(impls[1].class(reader) == def);
+
+    match impls[1].body(reader) {
+        MethodDefOrRef::MethodDef(body) => assert!(body.name(reader) == "Close"),
+        _ => panic!("Expected a MethodDef"),
+    }
+
+    match impls[1].declaration(reader) {
+        MethodDefOrRef::MemberRef(declaration) => {
+            assert!(declaration.name(reader) == "Close");
+            match declaration.parent(reader) {
+                MemberRefParent::TypeRef(parent) => {
+                    assert!(parent.name(reader) == ("Windows.Foundation", "IClosable"))
+                }
+                _ => panic!("Expected a TypeRef"),
+            }
+        }
+        _ => panic!("Expected a MemberRef"),
+    }
+}
@@ -0,0 +1,15 @@
+#[test]
+fn properties() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IAsyncInfo"));
+    let properties: Vec<winmd::parsed::Property> = def.properties(reader).collect();
+    assert!(properties.len() == 3);
+    assert!(properties[0].name(reader) == "ErrorCode");
+    assert!(properties[1].name(reader) == "Id");
+    assert!(properties[2].name(reader) == "Status");
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IStringable"));
+    assert!(def.properties(reader).next().is_none());
+}
@@ -0,0 +1,42 @@
+use winmd::parsed::*;
+
+#[test]
+fn semantics() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IAsyncActionWithProgress`1"));
+    let methods: Vec<MethodDef> = def.methods(reader).collect();
+    assert!(methods[0].name(reader) == "put_Progress");
+    assert!(methods[0].category(reader) == MethodCategory::Set);
+    assert!(methods[1].category(reader) == MethodCategory::Get);
+    assert!(methods[4].name(reader) == "GetResults");
+    assert!(methods[4].semantics(reader).is_none());
+    assert!(methods[4].category(reader) == MethodCategory::Normal);
+
+    let semantics = methods[1].semantics(reader).unwrap();
+    assert!(semantics.role(reader) == MethodRole::Getter);
+    assert!(semantics.method(reader) == methods[1]);
+    match semantics.association(reader) {
+        HasSemantics::Property(property) => {
+            assert!(property.name(reader) == "Progress");
+            assert!(property.methods(reader).count() == 2);
+        }
+        _ => panic!("Expected a property"),
+    }
+
+    let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IObservableVector`1"));
+    let methods: Vec<MethodDef> = def.methods(reader).collect();
+    assert!(methods[0].category(reader) == MethodCategory::Add);
+    assert!(methods[1].category(reader) == MethodCategory::Remove);
+
+    let event = def.events(reader).next().unwrap();
+    let roles: Vec<MethodRole> = event.methods(reader).map(|m| m.role(reader)).collect();
+    assert!(roles.contains(&MethodRole::AddOn));
+    assert!(roles.contains(&MethodRole::RemoveOn));
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "DeferralCompletedHandler"));
+    for method in def.methods(reader) {
+        assert!(method.category(reader) == MethodCategory::Normal);
+    }
+}
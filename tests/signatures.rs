@@ -0,0 +1,41 @@
+use winmd::parsed::*;
+
+#[test]
+fn signatures() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IStringable"));
+    let method = def.methods(reader).next().unwrap();
+    let sig = method.signature(reader);
+    assert!(sig.calling_convention == MethodCallingConvention::Default);
+    assert!(sig.has_this);
+    assert!(sig.generic_count == 0);
+    assert!(sig.params.is_empty());
+
+    match sig.return_type.element_type {
+        ElementType::String => {}
+        _ => panic!("Expected a string"),
+    }
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "IDeferralFactory"));
+    let method = def.methods(reader).next().unwrap();
+    let sig = method.signature(reader);
+    assert!(sig.params.len() == 1);
+    assert!(!sig.params[0].sig_type.by_ref);
+    assert!(sig.params[0].param.unwrap().name(reader) == "handler");
+
+    match sig.params[0].sig_type.element_type {
+        ElementType::Class(class) => {
+            assert!(class.name(reader) == Some(("Windows.Foundation", "DeferralCompletedHandler")))
+        }
+        _ => panic!("Expected a class"),
+    }
+
+    match sig.return_type.element_type {
+        ElementType::Class(class) => {
+            assert!(class.name(reader) == Some(("Windows.Foundation", "Deferral")))
+        }
+        _ => panic!("Expected a class"),
+    }
+}
@@ -1,6 +1,6 @@
 #[test]
 fn stringable() {
-    let path = std::path::PathBuf::from("winmds\\Windows.Foundation.FoundationContract.winmd");
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
     let reader = &winmd::TypeReader::from_iter(vec![path]);
 
     let def = reader.resolve_type_def(("Windows.Foundation", "IStringable"));
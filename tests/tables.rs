@@ -0,0 +1,21 @@
+use winmd::{ColumnKind, TableIndex};
+
+#[test]
+fn tables() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let tables: Vec<TableIndex> = reader.tables(0).collect();
+    assert!(tables[0] == TableIndex::Module);
+    assert!(tables.contains(&TableIndex::Assembly));
+    assert!(!tables.contains(&TableIndex::ImplMap));
+    assert!(reader.row_count(0, TableIndex::Assembly) == 1);
+
+    let columns = TableIndex::Assembly.columns();
+    assert!(columns[4].name == "Name");
+    assert!(columns[4].kind == ColumnKind::String);
+
+    let row = reader.rows(0, TableIndex::Assembly).next().unwrap();
+    assert!(reader.values(row).len() == columns.len());
+    assert!(reader.str(row, 4) == "Windows.Foundation.FoundationContract");
+}
@@ -0,0 +1,44 @@
+use winmd::parsed::*;
+
+#[test]
+fn type_specs() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    let def = reader.resolve_type_def(("Windows.Foundation.Collections", "IMap`2"));
+    let interface = def.interfaces(reader).next().unwrap().interface(reader);
+
+    let spec = match interface {
+        TypeDefOrRef::TypeSpec(spec) => spec,
+        _ => panic!("Expected a TypeSpec"),
+    };
+
+    let instance = spec.decode(reader).unwrap();
+    assert!(
+        instance.generic_type.name(reader)
+            == Some(("Windows.Foundation.Collections", "IIterable`1"))
+    );
+    assert!(!instance.value_type);
+    assert!(instance.args.len() == 1);
+
+    match &instance.args[0] {
+        ElementType::GenericInst(pair) => {
+            assert!(
+                pair.generic_type.name(reader)
+                    == Some(("Windows.Foundation.Collections", "IKeyValuePair`2"))
+            );
+            assert!(pair.args == vec![ElementType::Var(0), ElementType::Var(1)]);
+        }
+        _ => panic!("Expected a generic instance"),
+    }
+
+    assert!(interface.name(reader) == Some(("Windows.Foundation.Collections", "IIterable`1")));
+    assert!(
+        interface.resolve(reader).unwrap().name(reader)
+            == ("Windows.Foundation.Collections", "IIterable`1")
+    );
+    assert!(interface.generic_args(reader) == instance.args);
+
+    let def = reader.resolve_type_def(("Windows.Foundation", "Deferral"));
+    assert!(def.extends(reader).generic_args(reader).is_empty());
+}
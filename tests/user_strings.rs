@@ -0,0 +1,15 @@
+#[test]
+fn user_strings() {
+    let path = std::path::PathBuf::from("winmds/Windows.Foundation.FoundationContract.winmd");
+    let reader = &winmd::TypeReader::from_iter(vec![path]);
+
+    assert!(reader.user_string(0, 0).unwrap().value.is_empty());
+    assert!(reader.user_string(0, u32::MAX).is_none());
+
+    let strings: Vec<winmd::parsed::UserString> = reader.user_strings(0).collect();
+    assert!(strings.len() == 1);
+    assert!(strings[0].index == 1);
+    assert!(strings[0].value == " ");
+    assert!(!strings[0].special);
+    assert!(reader.user_string(0, 1).unwrap() == strings[0]);
+}